@@ -30,7 +30,7 @@ if (Test-Path $editLogPath) {
 # If substantial edits, suggest context review
 if ($editCount -gt 5) {
     $output = @{
-        followup_message = "Session had $editCount file edits. Consider: Does .ai/ai-summary.md or .ai/ai-context.md need updating with new patterns or learnings?"
+        followup_message = "Session had $editCount file edits. Consider: Does .ai/ai-summary.md or .ai/ai-context.md need updating with new patterns or learnings? Record them with ai-init learn add --section patterns|gotchas|decisions."
     } | ConvertTo-Json -Depth 10
     Write-Output $output
 } else {
@@ -0,0 +1,43 @@
+#!/bin/sh
+# extract-learnings.sh
+# Stop hook - Surfaces informative message after substantial sessions
+
+# Read input from stdin
+input=$(cat | tr -d '\n')
+
+# Print a top-level scalar field from the input JSON
+json_field() {
+    printf '%s' "$input" | sed -n "s/.*\"$1\"[[:space:]]*:[[:space:]]*\"\{0,1\}\([^\",}]*\).*/\1/p"
+}
+
+# Only process on successful completion
+if [ "$(json_field status)" != "completed" ]; then
+    echo '{}'
+    exit 0
+fi
+
+# Check if we've already looped
+loop_count=$(json_field loop_count)
+if [ "${loop_count:-0}" -ge 1 ] 2> /dev/null; then
+    echo '{}'
+    exit 0
+fi
+
+# Count edits in this session (if available from edit log)
+edit_log="$(dirname "$0")/../../.ai/edit-log.txt"
+edit_count=0
+
+if [ -f "$edit_log" ]; then
+    # Count lines (each line is one edit)
+    edit_count=$(wc -l < "$edit_log" | tr -d ' ')
+fi
+
+# If substantial edits, suggest context review
+if [ "$edit_count" -gt 5 ]; then
+    printf '{"followup_message": "Session had %s file edits. Consider: Does .ai/ai-summary.md or .ai/ai-context.md need updating with new patterns or learnings? Record them with ai-init learn add --section patterns|gotchas|decisions."}\n' "$edit_count"
+else
+    # Light session - no followup needed
+    echo '{}'
+fi
+
+exit 0
@@ -0,0 +1,90 @@
+#!/bin/sh
+# inject-context.sh
+# SessionStart hook - Injects lean ai-summary.md + dynamic workflow state
+
+# Drain input from stdin (not needed for this hook)
+cat > /dev/null
+
+# Base path for .ai folder
+ai_path="$(dirname "$0")/../../.ai"
+
+# Escape stdin as the body of a JSON string (strips BOM and CR)
+json_escape() {
+    bom=$(printf '\357\273\277')
+    sed -e "1s/^$bom//" | tr -d '\r' \
+        | sed -e 's/\\/\\\\/g' -e 's/"/\\"/g' -e 's/	/\\t/g' \
+        | awk 'NR > 1 { printf "\\n" } { printf "%s", $0 }'
+}
+
+# Print a frontmatter field: frontmatter_field <file> <key>
+frontmatter_field() {
+    tr -d '\r' < "$1" | awk -v key="$2" '
+        NR == 1 { sub(/^\357\273\277/, ""); if ($0 !~ /^---[ \t]*$/) exit; next }
+        /^---[ \t]*$/ { exit }
+        index($0, key ":") == 1 {
+            value = substr($0, length(key) + 2)
+            gsub(/^[ \t]+|[ \t]+$/, "", value)
+            gsub(/^["\047]|["\047]$/, "", value)
+            print value
+            exit
+        }'
+}
+
+# 1. Read static summary (lean context)
+summary_file=""
+if [ -f "$ai_path/ai-summary.md" ]; then
+    summary_file="$ai_path/ai-summary.md"
+elif [ -f "$ai_path/ai-context.md" ]; then
+    # Fallback: Try ai-context.md if ai-summary.md doesn't exist yet
+    summary_file="$ai_path/ai-context.md"
+fi
+
+# 2. Detect workflow state from active files
+nl='
+'
+workflow_state="## Workflow State (auto-detected)$nl$nl"
+has_active_files=false
+
+research_file="$ai_path/active-research.md"
+if [ -f "$research_file" ]; then
+    has_active_files=true
+    task=$(frontmatter_field "$research_file" task)
+    status=$(frontmatter_field "$research_file" status)
+    workflow_state="$workflow_state- **Active research**: \`.ai/active-research.md\` (task: ${task:-unknown}, status: ${status:-research})$nl"
+fi
+
+plan_file="$ai_path/active-plan.md"
+if [ -f "$plan_file" ]; then
+    has_active_files=true
+    task=$(frontmatter_field "$plan_file" task)
+    status=$(frontmatter_field "$plan_file" status)
+    status=${status:-planning}
+    workflow_state="$workflow_state- **Active plan**: \`.ai/active-plan.md\` (task: ${task:-unknown}, status: $status)$nl"
+
+    # Add helpful tip based on status
+    if [ "$status" = "planning" ]; then
+        workflow_state="$workflow_state${nl}Tip: Run \`/implement\` when ready to execute this plan.$nl"
+    elif [ "$status" = "implementing" ]; then
+        workflow_state="$workflow_state${nl}Tip: Implementation in progress. Follow the plan step by step.$nl"
+    fi
+fi
+
+# If no active files, indicate clean slate
+if [ "$has_active_files" = false ]; then
+    workflow_state="${workflow_state}No active research or plan. Use \`/research [task]\` to start a new task.$nl"
+fi
+
+# 3. Build combined context
+if [ -n "$summary_file" ]; then
+    context=$(
+        printf '# Project Summary (from .ai/ai-summary.md)\n\n'
+        cat "$summary_file"
+        printf '\n\n---\n\n%s\n---\n\nFor full patterns and constraints, reference `.ai/ai-context.md`.' "$workflow_state"
+    )
+    printf '{"additional_context": "%s"}\n' "$(printf '%s' "$context" | json_escape)"
+else
+    # No summary file - return empty but inform about setup
+    printf '{"additional_context": "%s"}\n' "No .ai/ai-summary.md found. Run \`ai-init\` to set up AI workflow templates."
+fi
+
+exit 0
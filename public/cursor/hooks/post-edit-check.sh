@@ -0,0 +1,79 @@
+#!/bin/sh
+# post-edit-check.sh
+# AfterFileEdit hook - Validates AI edits and warns about common mistakes
+
+# Read input from stdin
+input=$(cat | tr -d '\n')
+
+file_path=$(printf '%s' "$input" | sed -n 's/.*"file_path"[[:space:]]*:[[:space:]]*"\([^"]*\)".*/\1/p')
+edit_count=$(printf '%s' "$input" | grep -o '"old_string"' | wc -l | tr -d ' ')
+
+# Log the edit for audit trail
+log_path="$(dirname "$0")/../../.ai/edit-log.txt"
+timestamp=$(date '+%Y-%m-%d %H:%M:%S')
+echo "[$timestamp] Edited: $file_path ($edit_count change(s))" >> "$log_path" 2> /dev/null
+
+# Get lowercase file extension
+extension=$(printf '%s' "${file_path##*.}" | tr '[:upper:]' '[:lower:]')
+
+warnings=""
+add_warning() {
+    if [ -n "$warnings" ]; then
+        warnings="$warnings, "
+    fi
+    warnings="$warnings\"$1\""
+}
+
+# Only validate code files
+case "$extension" in
+    ts | tsx | js | jsx | py | rs | go | java | cs | cpp | c | rb | php)
+        if [ -f "$file_path" ]; then
+            # Check for console.log (JS/TS)
+            case "$extension" in
+                ts | tsx | js | jsx)
+                    grep -q 'console\.log(' "$file_path" \
+                        && add_warning "console.log found - consider using a logger"
+                    ;;
+            esac
+
+            # Check for 'any' type (TypeScript)
+            case "$extension" in
+                ts | tsx)
+                    grep -Eq ':[[:space:]]*any([^[:alnum:]_]|$)' "$file_path" \
+                        && add_warning "': any' type found - consider explicit typing"
+                    ;;
+            esac
+
+            # Check for TODO/FIXME comments (all languages)
+            grep -Eq '(^|[^[:alnum:]_])(TODO|FIXME|XXX|HACK)([^[:alnum:]_]|$)' "$file_path" \
+                && add_warning "TODO/FIXME comment found - AI may have left placeholder"
+
+            # Check for commented-out code blocks (heuristic: multiple consecutive comment lines)
+            awk '/^[ \t]*(\/\/|#|\/\*|\*)[ \t]*[^ \t]/ { if (++streak >= 5) { found = 1; exit } next } { streak = 0 } END { exit !found }' "$file_path" \
+                && add_warning "Large commented block found - may be dead code"
+
+            # Check for print statements (Python)
+            if [ "$extension" = "py" ]; then
+                grep -Eq '(^|[^[:alnum:]_])print[[:space:]]*\(' "$file_path" \
+                    && add_warning "print() found - consider using logging"
+            fi
+
+            # Check for unwrap/expect without context (Rust)
+            if [ "$extension" = "rs" ]; then
+                grep -Eq '\.(unwrap|expect)[[:space:]]*\([[:space:]]*\)' "$file_path" \
+                    && add_warning ".unwrap() or .expect() with no message found - consider proper error handling"
+            fi
+        fi
+        ;;
+esac
+
+# Build output
+if [ -n "$warnings" ]; then
+    count=$(printf '%s' "$warnings" | grep -o '", "' | wc -l | tr -d ' ')
+    count=$((count + 1))
+    printf '{"warnings": [%s], "message": "Validation found %s potential issue(s) in %s"}\n' "$warnings" "$count" "$file_path"
+else
+    echo '{}'
+fi
+
+exit 0
@@ -0,0 +1,623 @@
+use super::new::{normalize_slug, read_template, title_from_slug, DocKind};
+use crate::embed::frontmatter;
+use crate::git::find_repo_root;
+use console::style;
+use std::path::Path;
+use thiserror::Error;
+
+/// Directory the `/adr` command and `ai-init adr new` write to, relative to repo root
+pub const ADR_DIR: &str = ".ai/adr";
+
+/// Generated list of every record, relative to repo root
+pub const INDEX_PATH: &str = ".ai/adr/index.md";
+
+const INDEX_HEADER: &str = "# Architecture Decision Records\n\n\
+> Generated by `ai-init adr`. Edit the records, not this file.\n";
+
+#[derive(Error, Debug)]
+pub enum AdrError {
+    #[error("Not in a git repository")]
+    NotInGitRepo,
+    #[error("Invalid slug: {0:?}. Use a short kebab-case name such as `use-sqlite`")]
+    InvalidSlug(String),
+    #[error("Invalid ADR number: {0}. Use e.g. 3, 003 or ADR-003")]
+    InvalidNumber(String),
+    #[error("Invalid status: {0}. Valid: proposed, accepted, superseded, deprecated")]
+    InvalidStatus(String),
+    #[error("No ADR-{0:03} in {ADR_DIR}")]
+    NotFound(u32),
+    #[error("An ADR for `{0}` already exists: {1}")]
+    Duplicate(String, String),
+    #[error("ADR-{number:03} is {from}; it can't become {to}")]
+    InvalidTransition {
+        number: u32,
+        from: &'static str,
+        to: &'static str,
+    },
+    #[error("Use `ai-init adr supersede {0} <new>` so both records link to each other")]
+    UseSupersede(u32),
+    #[error("An ADR can't supersede itself")]
+    SelfSupersede,
+    #[error("ADR-{number:03} is {status}; only a proposed or accepted ADR can supersede another")]
+    InvalidSuccessor { number: u32, status: &'static str },
+    #[error("No decision template found")]
+    MissingTemplate,
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Lifecycle of a decision record
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdrStatus {
+    Proposed,
+    Accepted,
+    Superseded,
+    Deprecated,
+}
+
+impl AdrStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Proposed => "proposed",
+            Self::Accepted => "accepted",
+            Self::Superseded => "superseded",
+            Self::Deprecated => "deprecated",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "proposed" => Some(Self::Proposed),
+            "accepted" => Some(Self::Accepted),
+            "superseded" => Some(Self::Superseded),
+            "deprecated" => Some(Self::Deprecated),
+            _ => None,
+        }
+    }
+
+    /// Capitalized form used in the record body
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Proposed => "Proposed",
+            Self::Accepted => "Accepted",
+            Self::Superseded => "Superseded",
+            Self::Deprecated => "Deprecated",
+        }
+    }
+
+    /// Superseded and deprecated records are final
+    fn can_become(&self, next: AdrStatus) -> bool {
+        matches!(
+            (self, next),
+            (Self::Proposed, Self::Accepted)
+                | (
+                    Self::Proposed | Self::Accepted,
+                    Self::Superseded | Self::Deprecated
+                )
+        )
+    }
+}
+
+/// One `adr-NNN-slug.md` file
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub number: u32,
+    pub file_name: String,
+    pub title: String,
+    pub status: AdrStatus,
+    pub date: Option<String>,
+    pub supersedes: Option<u32>,
+    pub superseded_by: Option<u32>,
+}
+
+impl Record {
+    fn path(&self) -> String {
+        format!("{}/{}", ADR_DIR, self.file_name)
+    }
+
+    /// `[ADR-003](adr-003-slug.md)`, relative to the ADR directory
+    fn link(&self) -> String {
+        format!("[ADR-{:03}]({})", self.number, self.file_name)
+    }
+}
+
+/// Create the next numbered ADR for `slug`
+pub fn new(slug: String) -> Result<(), AdrError> {
+    let repo_root = find_repo_root()?.ok_or(AdrError::NotInGitRepo)?;
+    let slug = normalize_slug(&slug).ok_or(AdrError::InvalidSlug(slug))?;
+    let record = create(&repo_root, &slug)?;
+    write_index(&repo_root)?;
+
+    println!(
+        "{} Created {} (task: {}, status: {})",
+        style("✓").green(),
+        record.path(),
+        slug,
+        record.status.as_str()
+    );
+    Ok(())
+}
+
+/// Print every record, optionally only those with one status
+pub fn list(status: Option<String>) -> Result<(), AdrError> {
+    let repo_root = find_repo_root()?.ok_or(AdrError::NotInGitRepo)?;
+    let status = status
+        .map(|s| AdrStatus::from_str(&s).ok_or(AdrError::InvalidStatus(s)))
+        .transpose()?;
+
+    let records: Vec<Record> = load(&repo_root)?
+        .into_iter()
+        .filter(|r| status.is_none_or(|s| r.status == s))
+        .collect();
+    if records.is_empty() {
+        println!(
+            "{} No ADRs in {}. Create one with `ai-init adr new <slug>`",
+            style("-").yellow(),
+            ADR_DIR
+        );
+        return Ok(());
+    }
+
+    for record in &records {
+        let mut line = format!(
+            "ADR-{:03}  {:<10}  {:<10}  {}",
+            record.number,
+            record.status.as_str(),
+            record.date.as_deref().unwrap_or("-"),
+            record.title
+        );
+        if let Some(by) = record.superseded_by {
+            line.push_str(&format!(" (superseded by ADR-{:03})", by));
+        }
+        if let Some(old) = record.supersedes {
+            line.push_str(&format!(" (supersedes ADR-{:03})", old));
+        }
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Print one record
+pub fn show(number: String) -> Result<(), AdrError> {
+    let repo_root = find_repo_root()?.ok_or(AdrError::NotInGitRepo)?;
+    let number = parse_number(&number).ok_or(AdrError::InvalidNumber(number))?;
+    let records = load(&repo_root)?;
+    let record = find(&records, number)?;
+
+    let content = std::fs::read_to_string(repo_root.join(record.path()))?;
+    println!("{}", style(record.path()).bold());
+    println!();
+    println!("{}", frontmatter::parse(&content).1.trim());
+    Ok(())
+}
+
+/// Move a record to accepted or deprecated
+pub fn status(number: String, status: String) -> Result<(), AdrError> {
+    let repo_root = find_repo_root()?.ok_or(AdrError::NotInGitRepo)?;
+    let number = parse_number(&number).ok_or(AdrError::InvalidNumber(number))?;
+    let next = AdrStatus::from_str(&status).ok_or(AdrError::InvalidStatus(status))?;
+    if next == AdrStatus::Superseded {
+        return Err(AdrError::UseSupersede(number));
+    }
+
+    let records = load(&repo_root)?;
+    let record = find(&records, number)?;
+    transition(&repo_root, record, next, None)?;
+    write_index(&repo_root)?;
+
+    println!(
+        "{} ADR-{:03} is now {}",
+        style("✓").green(),
+        number,
+        next.as_str()
+    );
+    Ok(())
+}
+
+/// Mark `number` as superseded by another record, given by number or as the slug of a new one.
+/// Both records link to each other, and a proposed successor is accepted.
+pub fn supersede(number: String, by: String) -> Result<(), AdrError> {
+    let repo_root = find_repo_root()?.ok_or(AdrError::NotInGitRepo)?;
+    let number = parse_number(&number).ok_or(AdrError::InvalidNumber(number))?;
+    let records = load(&repo_root)?;
+    let old = find(&records, number)?.clone();
+    if !old.status.can_become(AdrStatus::Superseded) {
+        return Err(AdrError::InvalidTransition {
+            number,
+            from: old.status.as_str(),
+            to: AdrStatus::Superseded.as_str(),
+        });
+    }
+
+    let new = match parse_number(&by) {
+        Some(by) => find(&records, by)?.clone(),
+        None => {
+            let slug = normalize_slug(&by).ok_or(AdrError::InvalidSlug(by))?;
+            let record = create(&repo_root, &slug)?;
+            println!("{} Created {}", style("✓").green(), record.path());
+            record
+        }
+    };
+    if new.number == old.number {
+        return Err(AdrError::SelfSupersede);
+    }
+    if !matches!(new.status, AdrStatus::Proposed | AdrStatus::Accepted) {
+        return Err(AdrError::InvalidSuccessor {
+            number: new.number,
+            status: new.status.as_str(),
+        });
+    }
+
+    transition(&repo_root, &old, AdrStatus::Superseded, Some(&new))?;
+    link_successor(&repo_root, &new, &old)?;
+    if new.status == AdrStatus::Proposed {
+        transition(&repo_root, &new, AdrStatus::Accepted, None)?;
+    }
+    write_index(&repo_root)?;
+
+    println!(
+        "{} ADR-{:03} is superseded by ADR-{:03}",
+        style("✓").green(),
+        old.number,
+        new.number
+    );
+    Ok(())
+}
+
+/// Write the next numbered record for `slug` from the decision template
+pub fn create(repo_root: &Path, slug: &str) -> Result<Record, AdrError> {
+    let records = load(repo_root)?;
+    if let Some(existing) = records
+        .iter()
+        .find(|r| parse_file_name(&r.file_name).is_some_and(|(_, s)| s == slug))
+    {
+        return Err(AdrError::Duplicate(slug.to_string(), existing.path()));
+    }
+
+    let number = records.iter().map(|r| r.number).max().unwrap_or(0) + 1;
+    let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let template = read_template(repo_root, DocKind::Decision).ok_or(AdrError::MissingTemplate)?;
+
+    let file_name = format!("adr-{:03}-{}.md", number, slug);
+    let content = instantiate(&template, number, slug, &date);
+    std::fs::create_dir_all(repo_root.join(ADR_DIR))?;
+    std::fs::write(repo_root.join(ADR_DIR).join(&file_name), &content)?;
+
+    Ok(Record {
+        number,
+        file_name,
+        title: title_from_slug(slug),
+        status: AdrStatus::Proposed,
+        date: Some(date),
+        supersedes: None,
+        superseded_by: None,
+    })
+}
+
+/// Decision template numbered, titled and dated, with the same frontmatter fields
+/// as the research and plan documents
+fn instantiate(template: &str, number: u32, slug: &str, date: &str) -> String {
+    let body = frontmatter::parse(template).1;
+    let body: Vec<String> = body
+        .lines()
+        .map(|line| {
+            if line.starts_with("# ADR-") {
+                format!("# ADR-{:03}: {}", number, title_from_slug(slug))
+            } else if line.starts_with("Date:") {
+                format!("Date: {}", date)
+            } else if line.starts_with("Status:") {
+                format!("Status: {}", AdrStatus::Proposed.label())
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+
+    let content = frontmatter::set(&(body.join("\n") + "\n"), "task", slug);
+    let content = frontmatter::set(&content, "date", date);
+    frontmatter::set(&content, "status", AdrStatus::Proposed.as_str())
+}
+
+/// Every record in the ADR directory, by number
+pub fn load(repo_root: &Path) -> Result<Vec<Record>, AdrError> {
+    let Ok(entries) = std::fs::read_dir(repo_root.join(ADR_DIR)) else {
+        return Ok(Vec::new());
+    };
+
+    let mut records = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+            continue;
+        };
+        if parse_file_name(&file_name).is_none() {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        records.extend(parse_record(file_name, &content));
+    }
+    records.sort_by_key(|r| r.number);
+    Ok(records)
+}
+
+fn find(records: &[Record], number: u32) -> Result<&Record, AdrError> {
+    records
+        .iter()
+        .find(|r| r.number == number)
+        .ok_or(AdrError::NotFound(number))
+}
+
+/// Read a record from its frontmatter, falling back to the `Status:` line
+/// of records written by hand or by the `/adr` command
+fn parse_record(file_name: String, content: &str) -> Option<Record> {
+    let (number, slug) = parse_file_name(&file_name)?;
+    let (fm, body) = frontmatter::parse(content);
+    let fm = fm.unwrap_or_default();
+    let field = |key: &str| {
+        fm.get_str(key)
+            .filter(|v| !v.starts_with('['))
+            .map(str::to_string)
+    };
+    let line = |prefix: &str| {
+        body.lines()
+            .find_map(|l| l.strip_prefix(prefix))
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty() && !v.starts_with('['))
+    };
+
+    let status_line = line("Status:");
+    let status = field("status")
+        .and_then(|s| AdrStatus::from_str(&s))
+        .or_else(|| {
+            let first = status_line.as_deref()?.split_whitespace().next()?;
+            AdrStatus::from_str(first)
+        })
+        .unwrap_or(AdrStatus::Proposed);
+
+    let superseded_by = field("superseded_by")
+        .and_then(|n| parse_number(&n))
+        .or_else(|| {
+            let (_, rest) = status_line.as_deref()?.split_once("ADR-")?;
+            parse_number(
+                &rest
+                    .chars()
+                    .take_while(char::is_ascii_digit)
+                    .collect::<String>(),
+            )
+        });
+
+    let title = body
+        .lines()
+        .find_map(|l| l.strip_prefix("# "))
+        .map(|t| t.split_once(": ").map_or(t, |(_, title)| title).trim())
+        .filter(|t| !t.is_empty() && !t.starts_with('['))
+        .map(str::to_string)
+        .unwrap_or_else(|| title_from_slug(slug));
+
+    Some(Record {
+        number,
+        title,
+        status,
+        date: field("date").or_else(|| line("Date:")),
+        supersedes: field("supersedes").and_then(|n| parse_number(&n)),
+        superseded_by,
+        file_name,
+    })
+}
+
+/// Number and slug of an `adr-NNN-slug.md` file name
+fn parse_file_name(name: &str) -> Option<(u32, &str)> {
+    let rest = name.strip_prefix("adr-")?.strip_suffix(".md")?;
+    let (number, slug) = rest.split_once('-').unwrap_or((rest, ""));
+    Some((number.parse().ok()?, slug))
+}
+
+/// `3`, `003`, `ADR-003` or `adr-3`
+fn parse_number(value: &str) -> Option<u32> {
+    let value = value.trim();
+    let digits = match value.get(..4) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("adr-") => &value[4..],
+        _ => value,
+    };
+    digits.parse().ok()
+}
+
+/// Update a record's status in its frontmatter and `Status:` line
+fn transition(
+    repo_root: &Path,
+    record: &Record,
+    next: AdrStatus,
+    successor: Option<&Record>,
+) -> Result<(), AdrError> {
+    if !record.status.can_become(next) {
+        return Err(AdrError::InvalidTransition {
+            number: record.number,
+            from: record.status.as_str(),
+            to: next.as_str(),
+        });
+    }
+
+    let path = repo_root.join(record.path());
+    let content = std::fs::read_to_string(&path)?;
+    let mut content = frontmatter::set(&content, "status", next.as_str());
+    let status_line = match successor {
+        Some(successor) => {
+            content = frontmatter::set(
+                &content,
+                "superseded_by",
+                &format!("{:03}", successor.number),
+            );
+            format!("Status: {} by {}", next.label(), successor.link())
+        }
+        None => format!("Status: {}", next.label()),
+    };
+
+    std::fs::write(&path, set_line(&content, "Status:", &status_line, None))?;
+    Ok(())
+}
+
+/// Record in the successor which decision it replaces
+fn link_successor(repo_root: &Path, record: &Record, old: &Record) -> Result<(), AdrError> {
+    let path = repo_root.join(record.path());
+    let content = std::fs::read_to_string(&path)?;
+    let content = frontmatter::set(&content, "supersedes", &format!("{:03}", old.number));
+    let line = format!("Supersedes: {}", old.link());
+    std::fs::write(
+        &path,
+        set_line(&content, "Supersedes:", &line, Some("Status:")),
+    )?;
+    Ok(())
+}
+
+/// Replace the first body line starting with `prefix`, or insert `line` after the
+/// line starting with `after`. Content is returned unchanged if neither exists.
+fn set_line(content: &str, prefix: &str, line: &str, after: Option<&str>) -> String {
+    let (_, body) = frontmatter::parse(content);
+    let head = &content[..content.len() - body.len()];
+
+    let mut lines: Vec<String> = body.lines().map(str::to_string).collect();
+    if let Some(i) = lines.iter().position(|l| l.starts_with(prefix)) {
+        lines[i] = line.to_string();
+    } else if let Some(i) = after.and_then(|a| lines.iter().position(|l| l.starts_with(a))) {
+        lines.insert(i + 1, line.to_string());
+    }
+    format!("{}{}\n", head, lines.join("\n"))
+}
+
+/// Regenerate the index from the records
+fn write_index(repo_root: &Path) -> Result<(), AdrError> {
+    let records = load(repo_root)?;
+    let mut index = format!(
+        "{}\n| ADR | Title | Status | Date |\n|-----|-------|--------|------|\n",
+        INDEX_HEADER
+    );
+    for record in &records {
+        let mut status = record.status.label().to_string();
+        if let Some(by) = record.superseded_by.and_then(|n| find(&records, n).ok()) {
+            status.push_str(&format!(" by {}", by.link()));
+        }
+        if let Some(old) = record.supersedes.and_then(|n| find(&records, n).ok()) {
+            status.push_str(&format!(", supersedes {}", old.link()));
+        }
+        index.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            record.link(),
+            record.title.replace('|', "\\|"),
+            status,
+            record.date.as_deref().unwrap_or("")
+        ));
+    }
+
+    std::fs::write(repo_root.join(INDEX_PATH), index)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_number_accepts_plain_and_prefixed_numbers() {
+        assert_eq!(parse_number("ADR-003"), Some(3));
+        assert_eq!(parse_number("adr-12"), Some(12));
+        assert_eq!(parse_number(" 3 "), Some(3));
+        assert_eq!(parse_number("adr-"), None);
+        assert_eq!(parse_number("three"), None);
+    }
+
+    #[test]
+    fn parse_record_reads_the_status_line_without_frontmatter() {
+        let content = "# ADR-002: Use Postgres\n\n\
+            Status: Superseded by [ADR-004](adr-004-use-sqlite.md)\n\
+            Date: 2026-01-02\n\n## Context\n";
+        let record = parse_record("adr-002-use-postgres.md".to_string(), content).unwrap();
+        assert_eq!(record.number, 2);
+        assert_eq!(record.title, "Use Postgres");
+        assert_eq!(record.status, AdrStatus::Superseded);
+        assert_eq!(record.superseded_by, Some(4));
+        assert_eq!(record.date.as_deref(), Some("2026-01-02"));
+    }
+
+    #[test]
+    fn parse_record_prefers_frontmatter() {
+        let content =
+            "---\nstatus: accepted\nsupersedes: 001\n---\n\n# ADR-002: Title\n\nStatus: Proposed\n";
+        let record = parse_record("adr-002-title.md".to_string(), content).unwrap();
+        assert_eq!(record.status, AdrStatus::Accepted);
+        assert_eq!(record.supersedes, Some(1));
+        assert!(parse_record("notes.md".to_string(), content).is_none());
+    }
+
+    #[test]
+    fn set_line_inserts_after_status_and_keeps_frontmatter() {
+        let frontmatter = "---\nstatus: accepted\ntitle: \"Keep: me\"   \n---\n";
+        let content = format!(
+            "{}\n# ADR-004: Title\n\nStatus: Accepted\nDate: 2026-01-02\n",
+            frontmatter
+        );
+
+        let updated = set_line(
+            &content,
+            "Supersedes:",
+            "Supersedes: [ADR-002](x.md)",
+            Some("Status:"),
+        );
+        assert!(updated.starts_with(frontmatter));
+        assert!(
+            updated.ends_with("Status: Accepted\nSupersedes: [ADR-002](x.md)\nDate: 2026-01-02\n")
+        );
+
+        // An existing line is replaced in place
+        let replaced = set_line(
+            &updated,
+            "Supersedes:",
+            "Supersedes: [ADR-003](y.md)",
+            Some("Status:"),
+        );
+        assert!(replaced.starts_with(frontmatter));
+        assert_eq!(replaced.matches("Supersedes:").count(), 1);
+        assert!(replaced.contains("Supersedes: [ADR-003](y.md)\nDate:"));
+
+        // Neither line nor anchor: unchanged
+        assert_eq!(
+            set_line(&content, "Missing:", "Missing: x", Some("Nowhere:")),
+            content
+        );
+    }
+
+    #[test]
+    fn final_and_backward_transitions_are_rejected() {
+        assert!(!AdrStatus::Accepted.can_become(AdrStatus::Proposed));
+        assert!(!AdrStatus::Superseded.can_become(AdrStatus::Accepted));
+        assert!(!AdrStatus::Deprecated.can_become(AdrStatus::Superseded));
+        assert!(AdrStatus::Proposed.can_become(AdrStatus::Accepted));
+        assert!(AdrStatus::Accepted.can_become(AdrStatus::Superseded));
+    }
+
+    #[test]
+    fn transition_updates_frontmatter_and_status_line() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(ADR_DIR)).unwrap();
+        let write = |name: &str, content: &str| {
+            std::fs::write(dir.path().join(ADR_DIR).join(name), content).unwrap();
+            parse_record(name.to_string(), content).unwrap()
+        };
+        let old = write(
+            "adr-001-old.md",
+            "---\nstatus: accepted\n---\n\n# ADR-001: Old\n\nStatus: Accepted\n\nBody.\n",
+        );
+        let new = write("adr-002-new.md", "# ADR-002: New\n\nStatus: Proposed\n");
+
+        transition(dir.path(), &old, AdrStatus::Superseded, Some(&new)).unwrap();
+        let content = std::fs::read_to_string(dir.path().join(old.path())).unwrap();
+        let record = parse_record(old.file_name.clone(), &content).unwrap();
+        assert_eq!(record.status, AdrStatus::Superseded);
+        assert_eq!(record.superseded_by, Some(2));
+        assert!(content.contains("\nStatus: Superseded by [ADR-002](adr-002-new.md)\n\nBody.\n"));
+
+        assert!(matches!(
+            transition(dir.path(), &record, AdrStatus::Accepted, None),
+            Err(AdrError::InvalidTransition { number: 1, .. })
+        ));
+    }
+}
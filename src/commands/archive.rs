@@ -0,0 +1,287 @@
+use super::new::{normalize_slug, DocKind};
+use super::workflow::{Phase, State};
+use crate::embed::frontmatter;
+use crate::git::{commits_since, find_repo_root};
+use console::style;
+use std::path::Path;
+use thiserror::Error;
+
+/// Finished tasks, one `YYYY-MM-DD-<task>/` directory each, relative to repo root
+pub const ARCHIVE_DIR: &str = ".ai/archive";
+
+/// Generated list of archived tasks, relative to repo root
+pub const INDEX_PATH: &str = ".ai/archive/index.md";
+
+const INDEX_HEADER: &str = "# Archived Tasks\n\n\
+> Generated by `ai-init archive`. Search it with `ai-init archive --search <text>`.\n";
+
+/// Matching lines shown per archived task when searching
+const SEARCH_LINES: usize = 3;
+
+#[derive(Error, Debug)]
+pub enum ArchiveError {
+    #[error("Not in a git repository")]
+    NotInGitRepo,
+    #[error("No .ai/active-research.md or .ai/active-plan.md to archive")]
+    NothingToArchive,
+    #[error("Task `{task}` is still in {phase}. Run `ai-init workflow advance` or `ai-init workflow abort` first, or pass --force")]
+    InProgress { task: String, phase: &'static str },
+    #[error("The active documents have no task in their frontmatter")]
+    MissingTask,
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Move the active research and plan into the archive with their outcome and commits
+pub fn run(
+    outcome: Option<String>,
+    commits: Option<String>,
+    force: bool,
+) -> Result<(), ArchiveError> {
+    let repo_root = find_repo_root()?.ok_or(ArchiveError::NotInGitRepo)?;
+    let state = State::load(&repo_root)?;
+    let docs: Vec<_> = [&state.research, &state.plan]
+        .into_iter()
+        .flatten()
+        .collect();
+    if docs.is_empty() {
+        return Err(ArchiveError::NothingToArchive);
+    }
+
+    let task = state.task().ok_or(ArchiveError::MissingTask)?.to_string();
+    let phase = state.phase();
+    if !force
+        && matches!(
+            phase,
+            Phase::Research | Phase::Planning | Phase::Implementing
+        )
+    {
+        return Err(ArchiveError::InProgress {
+            task,
+            phase: phase.as_str(),
+        });
+    }
+
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let outcome = outcome.unwrap_or_else(|| default_outcome(&state).to_string());
+    let commits = commits.or_else(|| commit_range(&repo_root, &state));
+
+    let dir = archive_dir(
+        &repo_root,
+        &today,
+        &normalize_slug(&task).unwrap_or_else(|| "task".to_string()),
+    );
+    std::fs::create_dir_all(repo_root.join(&dir))?;
+    for doc in docs {
+        let kind = if doc.path == DocKind::Research.active_path() {
+            DocKind::Research
+        } else {
+            DocKind::Plan
+        };
+        let source = repo_root.join(&doc.path);
+        let content = std::fs::read_to_string(&source)?;
+        let content = frontmatter::set(&content, "outcome", &outcome);
+        let content = frontmatter::set(&content, "archived", &today);
+        let content = match &commits {
+            Some(commits) => frontmatter::set(&content, "commits", commits),
+            None => content,
+        };
+
+        let target = format!("{}/{}.md", dir, kind.as_str());
+        std::fs::write(repo_root.join(&target), content)?;
+        std::fs::remove_file(&source)?;
+        println!("{} Moved {} to {}", style("✓").green(), doc.path, target);
+    }
+
+    write_index(&repo_root)?;
+    println!(
+        "{} Archived task {} (outcome: {}, commits: {})",
+        style("✓").green(),
+        task,
+        outcome,
+        commits.as_deref().unwrap_or("none")
+    );
+    Ok(())
+}
+
+/// Print archived tasks with a line matching `query`, case-insensitively
+pub fn search(query: String) -> Result<(), ArchiveError> {
+    let repo_root = find_repo_root()?.ok_or(ArchiveError::NotInGitRepo)?;
+    let needle = query.to_lowercase();
+    let mut found = 0;
+
+    for entry in entries(&repo_root)? {
+        let mut matches: Vec<String> = Vec::new();
+        for (_, content) in &entry.docs {
+            let (_, body) = frontmatter::parse(content);
+            matches.extend(
+                body.lines()
+                    .map(str::trim)
+                    .filter(|l| l.to_lowercase().contains(&needle))
+                    .map(str::to_string),
+            );
+        }
+        let in_meta = [&entry.task, &entry.outcome]
+            .iter()
+            .any(|v| v.to_lowercase().contains(&needle));
+        if matches.is_empty() && !in_meta {
+            continue;
+        }
+
+        found += 1;
+        println!(
+            "{} {}/{} (task: {}, outcome: {})",
+            style("→").blue(),
+            ARCHIVE_DIR,
+            entry.dir,
+            entry.task,
+            entry.outcome
+        );
+        for line in matches.iter().take(SEARCH_LINES) {
+            println!("    {}", line);
+        }
+    }
+
+    if found == 0 {
+        println!(
+            "{} No archived task mentions {:?}",
+            style("-").yellow(),
+            query
+        );
+    }
+    Ok(())
+}
+
+/// Shipped when the plan was done, aborted when the task was, else unfinished
+fn default_outcome(state: &State) -> &'static str {
+    let statuses: Vec<&str> = [&state.research, &state.plan]
+        .into_iter()
+        .flatten()
+        .map(|d| d.status.as_str())
+        .collect();
+    if statuses.contains(&"aborted") {
+        "aborted"
+    } else if state.phase() == Phase::Done {
+        "shipped"
+    } else {
+        "unfinished"
+    }
+}
+
+/// `<base>..<last>` covering the commits made since the task was created
+fn commit_range(repo_root: &Path, state: &State) -> Option<String> {
+    let created = [&state.research, &state.plan]
+        .into_iter()
+        .flatten()
+        .find_map(|d| {
+            let content = std::fs::read_to_string(repo_root.join(&d.path)).ok()?;
+            let fm = frontmatter::parse(&content).0?;
+            fm.get_str("created")
+                .filter(|v| !v.starts_with('['))
+                .map(str::to_string)
+        })?;
+
+    let (commits, base) = commits_since(repo_root, &created)?;
+    let last = commits.last()?;
+    Some(match base {
+        Some(base) => format!("{}..{}", base, last),
+        // The task began with the root commit, so all of history up to `last` is its range
+        None => last.clone(),
+    })
+}
+
+/// `YYYY-MM-DD-<task>`, with a numeric suffix if that directory is taken
+fn archive_dir(repo_root: &Path, date: &str, task: &str) -> String {
+    let base = format!("{}/{}-{}", ARCHIVE_DIR, date, task);
+    let mut dir = base.clone();
+    let mut n = 2;
+    while repo_root.join(&dir).exists() {
+        dir = format!("{}-{}", base, n);
+        n += 1;
+    }
+    dir
+}
+
+/// One archived task
+struct Entry {
+    dir: String,
+    task: String,
+    outcome: String,
+    commits: String,
+    docs: Vec<(String, String)>,
+}
+
+/// Archived tasks, oldest first
+fn entries(repo_root: &Path) -> std::io::Result<Vec<Entry>> {
+    let Ok(read) = std::fs::read_dir(repo_root.join(ARCHIVE_DIR)) else {
+        return Ok(Vec::new());
+    };
+
+    let mut entries = Vec::new();
+    for dir in read {
+        let path = dir?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let mut docs = Vec::new();
+        for kind in [DocKind::Research, DocKind::Plan] {
+            let file = format!("{}.md", kind.as_str());
+            if let Ok(content) = std::fs::read_to_string(path.join(&file)) {
+                docs.push((file, content));
+            }
+        }
+
+        let field = |key: &str| {
+            docs.iter().find_map(|(_, content)| {
+                frontmatter::parse(content)
+                    .0?
+                    .get_str(key)
+                    .map(str::to_string)
+            })
+        };
+        entries.push(Entry {
+            task: field("task").unwrap_or_else(|| name.clone()),
+            outcome: field("outcome").unwrap_or_else(|| "-".to_string()),
+            commits: field("commits").unwrap_or_else(|| "-".to_string()),
+            dir: name,
+            docs,
+        });
+    }
+    entries.sort_by(|a, b| a.dir.cmp(&b.dir));
+    Ok(entries)
+}
+
+/// Regenerate the index from the archive directories
+fn write_index(repo_root: &Path) -> std::io::Result<()> {
+    let mut index = format!(
+        "{}\n| Archived | Task | Outcome | Commits | Documents |\n|----------|------|---------|---------|-----------|\n",
+        INDEX_HEADER
+    );
+    for entry in entries(repo_root)? {
+        let date = entry.dir.get(..10).unwrap_or("");
+        let docs: Vec<String> = entry
+            .docs
+            .iter()
+            .map(|(file, _)| format!("[{}]({}/{})", file, entry.dir, file))
+            .collect();
+        index.push_str(&format!(
+            "| {} | {} | {} | `{}` | {} |\n",
+            date,
+            escape_cell(&entry.task),
+            escape_cell(&entry.outcome),
+            escape_cell(&entry.commits),
+            docs.join(", ")
+        ));
+    }
+    std::fs::write(repo_root.join(INDEX_PATH), index)
+}
+
+/// Keep a value from splitting its table cell
+fn escape_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
@@ -0,0 +1,271 @@
+use super::scan::{self, Scan};
+use super::ContextError;
+use crate::config::Config;
+use crate::embed::source::Source;
+use crate::embed::template::{self, Vars};
+use crate::embed::ArtifactKind;
+use crate::git::find_repo_root;
+use crate::stack::{self, Stack};
+use crate::targets::Overrides;
+use console::style;
+use dialoguer::Confirm;
+use std::path::Path;
+
+/// Marks sections the scan couldn't fill in
+const UNKNOWN: &str = "> **Not detected**: `ai-init context generate` couldn't infer this section from the repository. Fill it in from team knowledge.";
+
+/// Template sections that only the team can fill in
+const UNKNOWN_SECTIONS: &[&str] = &[
+    "Architectural Principles",
+    "Critical Constraints",
+    "Common Pitfalls",
+];
+
+const CONTEXT_PATH: &str = ".ai/ai-context.md";
+const DRAFT_PATH: &str = ".ai/ai-context.draft.md";
+
+/// Scan the repository and write a draft ai-context.md that follows the template's sections
+pub fn run(force: bool) -> Result<(), ContextError> {
+    let repo_root = find_repo_root()?.ok_or(ContextError::NotInGitRepo)?;
+
+    println!(
+        "{} Scanning repository at: {}",
+        style("→").blue(),
+        repo_root.display()
+    );
+
+    let stack = stack::detect(&repo_root);
+    let scan = scan::scan(&repo_root);
+    let template = context_template(&repo_root)?;
+    let draft = draft(
+        &template::render(&template, &Vars::detect(&repo_root, &stack)),
+        &scan,
+        &stack,
+    );
+
+    // Never replace an existing context without confirmation
+    let target = repo_root.join(CONTEXT_PATH);
+    let path = if target.exists() && !force {
+        println!("{} {} already exists", style("!").yellow(), CONTEXT_PATH);
+        let overwrite = Confirm::new()
+            .with_prompt("Overwrite it with the generated draft?")
+            .default(false)
+            .interact()
+            .unwrap_or(false);
+        if overwrite {
+            CONTEXT_PATH
+        } else {
+            DRAFT_PATH
+        }
+    } else {
+        CONTEXT_PATH
+    };
+
+    let path_on_disk = repo_root.join(path);
+    if let Some(parent) = path_on_disk.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path_on_disk, &draft)?;
+
+    if !stack.is_empty() {
+        println!("  {} Stack: {}", style("✓").green(), stack.describe());
+    }
+    println!(
+        "  {} {} top-level entries, {} modules, {} test directories, {} CI systems",
+        style("✓").green(),
+        scan.top_level.len() + scan.top_level_files.len(),
+        scan.modules.len(),
+        scan.test_dirs.len(),
+        scan.ci.len()
+    );
+    println!();
+    println!("{} Wrote {}", style("✓").green().bold(), path);
+    println!(
+        "  Sections marked \"Not detected\" need your input{}",
+        if path == DRAFT_PATH {
+            format!("; merge the draft into {} when ready", CONTEXT_PATH)
+        } else {
+            String::new()
+        }
+    );
+
+    Ok(())
+}
+
+/// The ai-context.md that init would install: the override, else the configured source's template
+fn context_template(repo_root: &Path) -> Result<String, ContextError> {
+    if let Some(template) = Overrides::load().get(CONTEXT_PATH) {
+        return Ok(template.to_string());
+    }
+    let config = Config::load_or_default(repo_root)?;
+    Ok(Source::parse(config.source.as_deref())
+        .load()?
+        .core
+        .into_iter()
+        .find(|a| a.kind == ArtifactKind::Context && a.name == "ai-context.md")
+        .map(|a| a.content.into_owned())
+        .unwrap_or_default())
+}
+
+/// Fill the rendered template with what the scan found
+fn draft(template: &str, scan: &Scan, stack: &Stack) -> String {
+    let mut doc = template.to_string();
+
+    replace_section(&mut doc, "System Overview", &overview(scan, stack));
+    replace_section(&mut doc, "Tooling", &tooling(scan, stack));
+    replace_section(&mut doc, "File/Module Map", &module_map(scan));
+
+    for heading in UNKNOWN_SECTIONS {
+        if let Some((start, _)) = section_bounds(&doc, heading) {
+            doc.insert_str(start, &format!("\n{}\n", UNKNOWN));
+        }
+    }
+
+    doc
+}
+
+fn overview(scan: &Scan, stack: &Stack) -> String {
+    let mut out = String::new();
+    out.push_str(&stack.overview());
+    out.push_str("\n\n**What this system does:**\n");
+    match &scan.readme_summary {
+        Some(summary) => out.push_str(&format!("{}\n", summary)),
+        None => out.push_str(&format!(
+            "{}\n[Describe in 200-300 words: the purpose, main functionality, and key user flows]\n",
+            UNKNOWN
+        )),
+    }
+    if !scan.readme_headings.is_empty() {
+        out.push_str(&format!(
+            "\n**README sections:** {}\n",
+            scan.readme_headings.join(" · ")
+        ));
+    }
+
+    out.push_str("\n**Key components and interactions:**\n\n");
+    let components: Vec<&scan::Module> = if scan.modules.is_empty() {
+        scan.top_level
+            .iter()
+            .filter(|m| m.source_files > 0)
+            .collect()
+    } else {
+        scan.modules.iter().take(8).collect()
+    };
+    if components.is_empty() {
+        out.push_str("- [Component 1]: [Purpose and role]\n");
+    }
+    for module in components {
+        out.push_str(&format!(
+            "- **`{}/`**: [Purpose and role] ({} source files)\n",
+            module.path, module.source_files
+        ));
+    }
+    out.push_str("- [How they interact]\n");
+
+    out.push_str(&format!(
+        "\n**Major architectural decisions:**\n\n{}\n\n1. **[Decision 1]**: [What and WHY]\n",
+        UNKNOWN
+    ));
+    out
+}
+
+fn tooling(scan: &Scan, stack: &Stack) -> String {
+    let mut out = stack.tooling();
+    for (system, workflows) in &scan.ci {
+        if workflows.is_empty() {
+            out.push_str(&format!("\n- **CI**: {}", system));
+        } else {
+            out.push_str(&format!(
+                "\n- **CI**: {} ({})",
+                system,
+                workflows.join(", ")
+            ));
+        }
+    }
+    if !scan.test_dirs.is_empty() {
+        let dirs: Vec<String> = scan
+            .test_dirs
+            .iter()
+            .take(8)
+            .map(|d| format!("`{}`", d))
+            .collect();
+        out.push_str(&format!("\n- **Test locations**: {}", dirs.join(", ")));
+    }
+    out.push('\n');
+    out
+}
+
+fn module_map(scan: &Scan) -> String {
+    let mut out = String::from("### Top-Level Layout\n\n");
+    for entry in &scan.top_level {
+        if entry.source_files > 0 {
+            out.push_str(&format!(
+                "- `{}/`: {} source files\n",
+                entry.path, entry.source_files
+            ));
+        } else {
+            out.push_str(&format!("- `{}/`\n", entry.path));
+        }
+    }
+    if !scan.top_level_files.is_empty() {
+        let files: Vec<String> = scan
+            .top_level_files
+            .iter()
+            .map(|f| format!("`{}`", f))
+            .collect();
+        out.push_str(&format!("- Files: {}\n", files.join(", ")));
+    }
+
+    out.push_str("\n### Core Modules\n\n");
+    if scan.modules.is_empty() {
+        out.push_str(&format!(
+            "{}\n\n- **[Module/Directory]**: [Purpose]\n",
+            UNKNOWN
+        ));
+    }
+    for module in &scan.modules {
+        out.push_str(&format!(
+            "- **{}/**: [Purpose] ({} files, {} lines)\n",
+            module.path, module.source_files, module.source_lines
+        ));
+        if let Some(key_file) = &module.key_file {
+            out.push_str(&format!("  - Key files: `{}` - [what it does]\n", key_file));
+        }
+    }
+
+    if !scan.largest_files.is_empty() {
+        out.push_str("\n### Largest Files\n\n");
+        for file in &scan.largest_files {
+            out.push_str(&format!("- `{}` ({} lines)\n", file.path, file.lines));
+        }
+    }
+
+    out.push_str(&format!(
+        "\n### Service Boundaries\n\n{}\n\n- **[Service A]** ↔ **[Service B]**: [How they interact]\n",
+        UNKNOWN
+    ));
+    out.push_str(&format!(
+        "\n### What Touches What\n\n{}\n\n- **[Component A]** depends on:\n",
+        UNKNOWN
+    ));
+    out
+}
+
+/// Byte range of a `## heading` section's body, up to the `---` separator or next section
+fn section_bounds(doc: &str, heading: &str) -> Option<(usize, usize)> {
+    let marker = format!("\n## {}\n", heading);
+    let start = doc.find(&marker)? + marker.len();
+    let rest = &doc[start..];
+    let end = [rest.find("\n---\n"), rest.find("\n## ")]
+        .into_iter()
+        .flatten()
+        .min()
+        .map_or(doc.len(), |i| start + i + 1);
+    Some((start, end))
+}
+
+fn replace_section(doc: &mut String, heading: &str, body: &str) {
+    if let Some((start, end)) = section_bounds(doc, heading) {
+        doc.replace_range(start..end, &format!("\n{}\n\n", body.trim_end()));
+    }
+}
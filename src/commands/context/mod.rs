@@ -0,0 +1,28 @@
+mod generate;
+mod scan;
+pub mod stats;
+mod summarize;
+
+pub use generate::run as generate;
+pub use stats::run as stats;
+pub use summarize::{run as summarize, DEFAULT_WORD_BUDGET as DEFAULT_SUMMARY_WORDS};
+
+use crate::config::ConfigError;
+use crate::embed::source::SourceError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContextError {
+    #[error("Not in a git repository")]
+    NotInGitRepo,
+    #[error("No .ai/ai-context.md found. Run `ai-init init` or `ai-init context generate` first")]
+    MissingContext,
+    #[error("Invalid target: {0}. Valid: {valid}", valid = crate::targets::valid_ids())]
+    InvalidTarget(String),
+    #[error(transparent)]
+    Source(#[from] SourceError),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,262 @@
+use std::path::Path;
+
+/// Directories never worth scanning: dependencies, build output and VCS data
+const IGNORED_DIRS: &[&str] = &[
+    "node_modules",
+    "target",
+    "dist",
+    "build",
+    "out",
+    "vendor",
+    "venv",
+    "__pycache__",
+    "bin",
+    "obj",
+    "coverage",
+];
+
+/// Extensions counted as source code
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "rs", "ts", "tsx", "js", "jsx", "mjs", "cjs", "py", "go", "java", "kt", "cs", "cpp", "cc", "c",
+    "h", "hpp", "rb", "php", "swift", "scala", "ex", "exs",
+];
+
+/// Directory names that hold tests
+const TEST_DIRS: &[&str] = &[
+    "test",
+    "tests",
+    "__tests__",
+    "spec",
+    "specs",
+    "e2e",
+    "testdata",
+];
+
+/// CI configuration files and directories, relative to repo root
+const CI_FILES: &[(&str, &str)] = &[
+    (".github/workflows", "GitHub Actions"),
+    (".gitlab-ci.yml", "GitLab CI"),
+    (".circleci/config.yml", "CircleCI"),
+    ("azure-pipelines.yml", "Azure Pipelines"),
+    ("Jenkinsfile", "Jenkins"),
+    (".travis.yml", "Travis CI"),
+    ("bitbucket-pipelines.yml", "Bitbucket Pipelines"),
+];
+
+/// Directories whose children are treated as the project's modules
+const SOURCE_ROOTS: &[&str] = &[
+    "src", "lib", "app", "pkg", "internal", "cmd", "packages", "apps",
+];
+
+const MAX_DEPTH: usize = 8;
+const MAX_FILES: usize = 20_000;
+
+/// A source file and its line count
+pub struct SourceFile {
+    pub path: String,
+    pub lines: usize,
+}
+
+/// A top-level entry or module directory with the source files under it
+pub struct Module {
+    pub path: String,
+    pub source_files: usize,
+    pub source_lines: usize,
+    /// Largest source file in the module
+    pub key_file: Option<String>,
+}
+
+/// What an offline scan of the repository found
+#[derive(Default)]
+pub struct Scan {
+    /// Top-level directories with their source totals
+    pub top_level: Vec<Module>,
+    /// Top-level files, excluding dotfiles
+    pub top_level_files: Vec<String>,
+    /// Subdirectories of src/, lib/, ... by size
+    pub modules: Vec<Module>,
+    pub readme_summary: Option<String>,
+    pub readme_headings: Vec<String>,
+    /// CI systems with their workflow/config names
+    pub ci: Vec<(&'static str, Vec<String>)>,
+    pub test_dirs: Vec<String>,
+    /// Largest source files, biggest first
+    pub largest_files: Vec<SourceFile>,
+}
+
+pub fn scan(repo_root: &Path) -> Scan {
+    let mut files: Vec<SourceFile> = Vec::new();
+    let mut test_dirs: Vec<String> = Vec::new();
+    walk(repo_root, repo_root, 0, &mut files, &mut test_dirs);
+    files.sort_by(|a, b| b.lines.cmp(&a.lines).then(a.path.cmp(&b.path)));
+    test_dirs.sort();
+
+    let mut top_level = Vec::new();
+    let mut top_level_files = Vec::new();
+    for (name, is_dir) in list_dir(repo_root) {
+        if is_dir {
+            top_level.push(module(&name, &files));
+        } else {
+            top_level_files.push(name);
+        }
+    }
+
+    let mut modules: Vec<Module> = SOURCE_ROOTS
+        .iter()
+        .filter(|root| repo_root.join(root).is_dir())
+        .flat_map(|root| {
+            list_dir(&repo_root.join(root))
+                .into_iter()
+                .filter(|(_, is_dir)| *is_dir)
+                .map(move |(name, _)| format!("{}/{}", root, name))
+        })
+        .map(|path| module(&path, &files))
+        .filter(|m| m.source_files > 0)
+        .collect();
+    modules.sort_by_key(|m| std::cmp::Reverse(m.source_lines));
+
+    let (readme_summary, readme_headings) = readme(repo_root);
+
+    Scan {
+        top_level,
+        top_level_files,
+        modules,
+        readme_summary,
+        readme_headings,
+        ci: ci(repo_root),
+        test_dirs,
+        largest_files: files.into_iter().take(10).collect(),
+    }
+}
+
+/// Visible entries of a directory, sorted, without ignored directories
+fn list_dir(dir: &Path) -> Vec<(String, bool)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut result: Vec<(String, bool)> = entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| {
+            let name = e.file_name().into_string().ok()?;
+            let is_dir = e.file_type().ok()?.is_dir();
+            let skip = name.starts_with('.') || (is_dir && IGNORED_DIRS.contains(&name.as_str()));
+            (!skip).then_some((name, is_dir))
+        })
+        .collect();
+    result.sort();
+    result
+}
+
+fn walk(
+    root: &Path,
+    dir: &Path,
+    depth: usize,
+    files: &mut Vec<SourceFile>,
+    test_dirs: &mut Vec<String>,
+) {
+    if depth > MAX_DEPTH || files.len() >= MAX_FILES {
+        return;
+    }
+
+    for (name, is_dir) in list_dir(dir) {
+        let path = dir.join(&name);
+        let relative = relative(root, &path);
+
+        if is_dir {
+            if TEST_DIRS.contains(&name.as_str()) {
+                test_dirs.push(format!("{}/", relative));
+            }
+            walk(root, &path, depth + 1, files, test_dirs);
+            continue;
+        }
+
+        let is_source = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| SOURCE_EXTENSIONS.contains(&e));
+        if is_source {
+            let lines = std::fs::read_to_string(&path)
+                .map(|c| c.lines().count())
+                .unwrap_or(0);
+            files.push(SourceFile {
+                path: relative,
+                lines,
+            });
+        }
+    }
+}
+
+fn relative(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Source totals for the files under `path`
+fn module(path: &str, files: &[SourceFile]) -> Module {
+    let prefix = format!("{}/", path);
+    let inside: Vec<&SourceFile> = files
+        .iter()
+        .filter(|f| f.path.starts_with(&prefix))
+        .collect();
+    Module {
+        path: path.to_string(),
+        source_files: inside.len(),
+        source_lines: inside.iter().map(|f| f.lines).sum(),
+        // `files` is sorted biggest first
+        key_file: inside.first().map(|f| f.path.clone()),
+    }
+}
+
+/// First prose paragraph and the section headings of the README
+fn readme(repo_root: &Path) -> (Option<String>, Vec<String>) {
+    let content = ["README.md", "readme.md", "Readme.md", "README"]
+        .iter()
+        .find_map(|name| std::fs::read_to_string(repo_root.join(name)).ok())
+        .unwrap_or_default()
+        .replace("\r\n", "\n");
+
+    let mut headings = Vec::new();
+    let mut in_code = false;
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        }
+        if let Some(heading) = line.strip_prefix("## ").filter(|_| !in_code) {
+            headings.push(heading.trim().to_string());
+        }
+    }
+
+    // Skip badges, images, HTML and quotes before the first real paragraph
+    let summary = content
+        .split("\n\n")
+        .map(str::trim)
+        .find(|p| {
+            !p.is_empty()
+                && !p.starts_with(['#', '[', '!', '<', '>', '`', '|', '-', '*'])
+                && !p.starts_with("---")
+        })
+        .map(|p| p.lines().map(str::trim).collect::<Vec<_>>().join(" "));
+
+    (summary, headings)
+}
+
+/// CI systems in use, with workflow names for GitHub Actions
+fn ci(repo_root: &Path) -> Vec<(&'static str, Vec<String>)> {
+    CI_FILES
+        .iter()
+        .filter(|(path, _)| repo_root.join(path).exists())
+        .map(|(path, name)| {
+            let mut workflows: Vec<String> = std::fs::read_dir(repo_root.join(path))
+                .into_iter()
+                .flatten()
+                .filter_map(|e| e.ok())
+                .filter_map(|e| e.file_name().into_string().ok())
+                .filter(|f| f.ends_with(".yml") || f.ends_with(".yaml"))
+                .collect();
+            workflows.sort();
+            (*name, workflows)
+        })
+        .collect()
+}
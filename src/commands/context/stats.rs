@@ -0,0 +1,345 @@
+use super::ContextError;
+use crate::config::Config;
+use crate::embed::markdown::estimate_tokens;
+use crate::embed::source::Source;
+use crate::embed::{frontmatter, Artifact, ArtifactKind};
+use crate::git::find_repo_root;
+use crate::targets::{self, overrides, GenerateOptions, Target, WriteMode};
+use console::style;
+use std::path::Path;
+
+/// Default token budget for what every session loads before any work starts
+pub const DEFAULT_ALWAYS_ON_BUDGET: usize = 4000;
+/// Default token budget for a single installed file
+pub const DEFAULT_FILE_BUDGET: usize = 2500;
+
+/// Context files hooks may inject at session start, in the order they are tried
+const INJECTED: &[&str] = &["ai-summary.md", "ai-context.md"];
+
+/// When an installed file is loaded into a session
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    AlwaysOn,   // every session
+    GlobScoped, // when matching files are in play
+    OnDemand,   // when invoked or requested: skills, commands, agents, references
+}
+
+impl Scope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AlwaysOn => "always-on",
+            Self::GlobScoped => "glob-scoped",
+            Self::OnDemand => "on-demand",
+        }
+    }
+}
+
+const SCOPES: &[Scope] = &[Scope::AlwaysOn, Scope::GlobScoped, Scope::OnDemand];
+
+/// Estimated cost of one installed file
+pub struct FileStats {
+    pub path: String,
+    pub kind: ArtifactKind,
+    pub scope: Scope,
+    pub tokens: usize,
+}
+
+/// Installed files of one target
+pub struct TargetStats {
+    pub id: &'static str,
+    pub files: Vec<FileStats>,
+    /// Session-start hooks inject the shared context for this target
+    pub injects_context: bool,
+}
+
+/// Installed files of every selected target plus the shared `.ai/` files
+pub struct Stats {
+    pub shared: Vec<FileStats>,
+    pub targets: Vec<TargetStats>,
+}
+
+/// Warning thresholds, in estimated tokens
+pub struct Thresholds {
+    pub always_on: usize,
+    pub file: usize,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            always_on: DEFAULT_ALWAYS_ON_BUDGET,
+            file: DEFAULT_FILE_BUDGET,
+        }
+    }
+}
+
+impl Stats {
+    /// Tokens loaded into every session of the target, including injected context
+    pub fn always_on(&self, target: &TargetStats) -> usize {
+        let injected = if target.injects_context {
+            total(&self.shared, Scope::AlwaysOn)
+        } else {
+            0
+        };
+        total(&target.files, Scope::AlwaysOn) + injected
+    }
+
+    /// Human-readable warnings for anything above the thresholds
+    pub fn warnings(&self, thresholds: &Thresholds) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for target in &self.targets {
+            let always_on = self.always_on(target);
+            if always_on > thresholds.always_on {
+                warnings.push(format!(
+                    "{}: ~{} always-on tokens per session (threshold {})",
+                    target.id, always_on, thresholds.always_on
+                ));
+            }
+        }
+
+        let files = self
+            .shared
+            .iter()
+            .chain(self.targets.iter().flat_map(|t| &t.files));
+        let mut seen: Vec<&str> = Vec::new();
+        for file in files {
+            if file.tokens > thresholds.file && !seen.contains(&file.path.as_str()) {
+                seen.push(&file.path);
+                warnings.push(format!(
+                    "{}: ~{} tokens (threshold {})",
+                    file.path, file.tokens, thresholds.file
+                ));
+            }
+        }
+        warnings
+    }
+}
+
+/// Estimate the token cost of the installed workflow files
+pub fn run(target: Option<Vec<String>>, thresholds: Thresholds) -> Result<(), ContextError> {
+    let repo_root = find_repo_root()?.ok_or(ContextError::NotInGitRepo)?;
+    let config = Config::load_or_default(&repo_root)?;
+    let selected = targets::resolve(target.or(config.targets.clone()), &repo_root)
+        .map_err(ContextError::InvalidTarget)?;
+
+    // The templates, overrides and settings init installs with
+    let options = GenerateOptions::from_config(&repo_root, &config)?;
+    let artifacts = Source::parse(config.source.as_deref()).load()?.select(
+        options.only.as_deref(),
+        options.skip.as_deref(),
+        &options.stack,
+    );
+    let stats = collect(&repo_root, &selected, &artifacts, &options);
+
+    println!(
+        "{} Estimated tokens (about four characters per token)",
+        style("→").blue()
+    );
+
+    if !stats.shared.is_empty() {
+        println!();
+        println!("{}", style("Shared (.ai/)").bold());
+        print_files(&stats.shared);
+    }
+
+    for target in &stats.targets {
+        println!();
+        if target.files.is_empty() {
+            println!("{} {}: no files installed", style("-").yellow(), target.id);
+            continue;
+        }
+        println!("{}", style(target.id).bold());
+        print_files(&target.files);
+        println!(
+            "  Per session: ~{} always-on{}",
+            stats.always_on(target),
+            if target.injects_context {
+                ", including injected context"
+            } else {
+                ""
+            }
+        );
+    }
+
+    let warnings = stats.warnings(&thresholds);
+    println!();
+    if warnings.is_empty() {
+        println!(
+            "{} Within thresholds ({} always-on, {} per file)",
+            style("✓").green(),
+            thresholds.always_on,
+            thresholds.file
+        );
+    }
+    for warning in &warnings {
+        println!("{} {}", style("!").yellow(), warning);
+    }
+
+    Ok(())
+}
+
+/// Files grouped by scope, with totals per scope and per category
+fn print_files(files: &[FileStats]) {
+    for scope in SCOPES {
+        let mut in_scope: Vec<&FileStats> = files.iter().filter(|f| f.scope == *scope).collect();
+        if in_scope.is_empty() {
+            continue;
+        }
+        in_scope.sort_by_key(|f| std::cmp::Reverse(f.tokens));
+
+        println!("  {:<12} ~{}", scope.as_str(), total(files, *scope));
+        for file in in_scope {
+            println!("    {:>6}  {}", file.tokens, file.path);
+        }
+    }
+
+    let mut categories: Vec<(ArtifactKind, usize, usize)> = Vec::new();
+    for file in files {
+        match categories.iter_mut().find(|(k, _, _)| *k == file.kind) {
+            Some((_, count, tokens)) => {
+                *count += 1;
+                *tokens += file.tokens;
+            }
+            None => categories.push((file.kind, 1, file.tokens)),
+        }
+    }
+    let categories: Vec<String> = categories
+        .iter()
+        .map(|(kind, count, tokens)| format!("{} {} (~{})", kind.as_str(), count, tokens))
+        .collect();
+    println!("  By category: {}", categories.join(", "));
+}
+
+fn total(files: &[FileStats], scope: Scope) -> usize {
+    files
+        .iter()
+        .filter(|f| f.scope == scope)
+        .map(|f| f.tokens)
+        .sum()
+}
+
+/// Measure the installed files of the given targets.
+/// Each artifact is rendered on its own so every file maps back to its source;
+/// extra override files are measured with the artifacts they sit among.
+pub fn collect(
+    repo_root: &Path,
+    selected: &[Box<dyn Target>],
+    artifacts: &[Artifact],
+    options: &GenerateOptions,
+) -> Stats {
+    // The first injectable context file that exists is what session start loads
+    let injected = INJECTED
+        .iter()
+        .find(|name| repo_root.join(".ai").join(name).exists());
+    let names = artifacts
+        .iter()
+        .filter(|a| a.kind == ArtifactKind::Context)
+        .map(|a| a.name.to_string());
+    let extras = options
+        .overrides
+        .paths()
+        .filter_map(|path| path.strip_prefix(".ai/"))
+        .map(str::to_string);
+    let mut shared: Vec<FileStats> = Vec::new();
+    for name in names.chain(extras) {
+        let path = format!(".ai/{}", name);
+        if shared.iter().any(|f| f.path == path) {
+            continue;
+        }
+        let scope = if injected.is_some_and(|injected| name == *injected) {
+            Scope::AlwaysOn
+        } else {
+            Scope::OnDemand
+        };
+        shared.extend(measure(repo_root, path, ArtifactKind::Context, scope));
+    }
+
+    let targets = selected
+        .iter()
+        .map(|target| target_stats(repo_root, target.as_ref(), artifacts, options))
+        .collect();
+
+    Stats { shared, targets }
+}
+
+fn target_stats(
+    repo_root: &Path,
+    target: &dyn Target,
+    artifacts: &[Artifact],
+    options: &GenerateOptions,
+) -> TargetStats {
+    let mut files: Vec<FileStats> = Vec::new();
+    let mut injects_context = false;
+
+    for artifact in artifacts.iter().filter(|a| a.kind != ArtifactKind::Context) {
+        for file in target.render(&[artifact], options) {
+            if !file.is_present(repo_root) {
+                continue;
+            }
+            // Hooks run code rather than add context
+            if artifact.kind == ArtifactKind::Hook {
+                injects_context = true;
+                continue;
+            }
+            if files.iter().any(|f| f.path == file.target_path) {
+                continue;
+            }
+
+            // Managed blocks merge rules into a file the tool always loads
+            let scope = match file.mode {
+                WriteMode::ManagedBlock { .. } => Scope::AlwaysOn,
+                WriteMode::Replace => scope(artifact.kind, &artifact.content),
+            };
+            files.extend(measure(repo_root, file.target_path, artifact.kind, scope));
+        }
+    }
+
+    // Extra override files in this target's layout
+    for path in options.overrides.paths() {
+        let Some((id, kind)) = overrides::owner(path) else {
+            continue;
+        };
+        if id != target.id() || files.iter().any(|f| f.path == path) {
+            continue;
+        }
+        let content = options.overrides.get(path).unwrap_or_default();
+        files.extend(measure(
+            repo_root,
+            path.to_string(),
+            kind,
+            scope(kind, content),
+        ));
+    }
+
+    TargetStats {
+        id: target.id(),
+        files,
+        injects_context,
+    }
+}
+
+/// Rules load according to their frontmatter; everything else on request
+fn scope(kind: ArtifactKind, content: &str) -> Scope {
+    if kind != ArtifactKind::Rule {
+        return Scope::OnDemand;
+    }
+    let meta = frontmatter::parse(content).0.unwrap_or_default();
+    if meta.get_bool("alwaysApply") {
+        Scope::AlwaysOn
+    } else if !meta.get_list("globs").is_empty() {
+        Scope::GlobScoped
+    } else {
+        Scope::OnDemand
+    }
+}
+
+/// Tokens of the file as installed, so local edits count
+fn measure(repo_root: &Path, path: String, kind: ArtifactKind, scope: Scope) -> Option<FileStats> {
+    let content = std::fs::read_to_string(repo_root.join(&path)).ok()?;
+    Some(FileStats {
+        path,
+        kind,
+        scope,
+        tokens: estimate_tokens(&content),
+    })
+}
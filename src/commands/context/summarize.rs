@@ -0,0 +1,284 @@
+use super::ContextError;
+use crate::embed::markdown::{condense, strip_title, word_count};
+use crate::embed::{self, frontmatter};
+use crate::git::find_repo_root;
+use console::style;
+use dialoguer::Confirm;
+use regex::{Captures, Regex};
+use std::sync::LazyLock;
+
+/// Word budget for ai-summary.md, matching the template's guidance
+pub const DEFAULT_WORD_BUDGET: usize = 200;
+
+const CONTEXT_PATH: &str = ".ai/ai-context.md";
+const SUMMARY_PATH: &str = ".ai/ai-summary.md";
+
+/// Identifies summaries written by this command, which are safe to regenerate
+const GENERATED_MARKER: &str = "Generated by `ai-init summarize`";
+
+/// Template guidance rather than project knowledge
+const SKIPPED_SECTIONS: &[&str] = &["Usage Notes", "Example Entries"];
+
+/// Condense .ai/ai-context.md into .ai/ai-summary.md within a word budget
+pub fn run(words: usize, force: bool) -> Result<(), ContextError> {
+    let repo_root = find_repo_root()?.ok_or(ContextError::NotInGitRepo)?;
+
+    let context_path = repo_root.join(CONTEXT_PATH);
+    if !context_path.exists() {
+        return Err(ContextError::MissingContext);
+    }
+    let context = std::fs::read_to_string(&context_path)?;
+    let summary = summarize(&context, words);
+
+    // Hand-written summaries are only replaced with confirmation;
+    // generated ones and the untouched template are fair game
+    let target = repo_root.join(SUMMARY_PATH);
+    let existing = std::fs::read_to_string(&target).unwrap_or_default();
+    let template = embed::get_all_artifacts()
+        .into_iter()
+        .find(|a| a.name == "ai-summary.md")
+        .map(|a| a.content)
+        .unwrap_or_default();
+    let hand_written =
+        !existing.is_empty() && existing != template && !existing.contains(GENERATED_MARKER);
+    if !force && hand_written {
+        println!(
+            "{} {} was not generated by ai-init summarize",
+            style("!").yellow(),
+            SUMMARY_PATH
+        );
+        let overwrite = Confirm::new()
+            .with_prompt("Overwrite it?")
+            .default(false)
+            .interact()
+            .unwrap_or(false);
+        if !overwrite {
+            println!("  {} {} (skipped)", style("-").yellow(), SUMMARY_PATH);
+            return Ok(());
+        }
+    }
+
+    if existing == summary {
+        println!("{} {} is up to date", style("✓").green(), SUMMARY_PATH);
+        return Ok(());
+    }
+
+    std::fs::write(&target, &summary)?;
+    println!(
+        "{} Wrote {} ({} of {} words)",
+        style("✓").green().bold(),
+        SUMMARY_PATH,
+        word_count(body(&summary)),
+        words
+    );
+
+    Ok(())
+}
+
+/// Deterministic summary of an ai-context.md: headings, decisions and bullet heads,
+/// taken round-robin across sections until the word budget is spent
+pub fn summarize(context: &str, budget: usize) -> String {
+    let context = context.replace("\r\n", "\n");
+    let (_, body) = frontmatter::parse(&context);
+    let condensed = condense(&project_lines(strip_title(body)));
+
+    let sections = sections(&condensed);
+    let chosen = select(&sections, budget);
+
+    let mut out = String::new();
+    for (section, chosen) in sections.iter().zip(&chosen) {
+        if !chosen.iter().any(|c| *c) {
+            continue;
+        }
+        out.push_str(&section.heading);
+        out.push('\n');
+        let mut context: &[String] = &[];
+        for (entry, _) in section.entries.iter().zip(chosen).filter(|(_, c)| **c) {
+            if entry.context != context {
+                for line in &entry.context {
+                    out.push_str(line);
+                    out.push('\n');
+                }
+                context = &entry.context;
+            }
+            out.push_str(&entry.line);
+            out.push('\n');
+        }
+    }
+
+    format!(
+        "# AI Summary\n\n\
+         > **Purpose**: Lean context injected at session start. For full details, reference `{context}`.\n\
+         > {marker} from `{context}`. Edit that file and re-run instead of editing this one.\n\n\
+         {body}\n---\n\n\
+         **Keep this under {budget} words.** Dynamic workflow state is auto-appended at session start.\n",
+        context = CONTEXT_PATH,
+        marker = GENERATED_MARKER,
+        // Headings are spaced the way condense spaces them
+        body = condense(&out),
+        budget = budget,
+    )
+}
+
+/// Body between the purpose blockquote and the footer rule
+fn body(summary: &str) -> &str {
+    let body = strip_title(summary);
+    body.rsplit_once("\n---\n").map_or(body, |(b, _)| b)
+}
+
+/// A bracketed phrase that isn't a link, e.g. "[Purpose and role]"
+static PLACEHOLDER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[([^\]\n]*)\](\()?").expect("placeholder pattern is valid"));
+
+/// Drop template guidance: skipped sections, example bullets and lines that are
+/// still `[placeholder]` text
+fn project_lines(body: &str) -> String {
+    let mut out = String::new();
+    let mut skipping = false;
+    for line in body.lines() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            skipping = SKIPPED_SECTIONS.contains(&heading.trim());
+        }
+        if skipping || line.trim_start().starts_with("- Example:") {
+            continue;
+        }
+
+        let stripped = PLACEHOLDER.replace_all(line, |caps: &Captures| {
+            // Links and task-list checkboxes are real content
+            if caps.get(2).is_some() || caps[1].trim().len() <= 1 {
+                caps[0].to_string()
+            } else {
+                String::new()
+            }
+        });
+        if stripped != line {
+            // What's left of a placeholder line, e.g. "- **Build**:" or "Key files: `` -"
+            let rest = stripped
+                .replace("``", "")
+                .replace("****", "")
+                .replace("  ", " ");
+            let rest = rest.trim_end_matches(|c: char| c.is_whitespace() || "-–*`".contains(c));
+            let text = rest.trim_start_matches(|c: char| {
+                c.is_whitespace() || c.is_ascii_digit() || "-.".contains(c)
+            });
+            if text.is_empty() || text.ends_with(':') || !text.chars().any(char::is_alphanumeric) {
+                continue;
+            }
+            out.push_str(rest);
+        } else {
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// A `##` section of the condensed context
+struct Section {
+    heading: String,
+    entries: Vec<Entry>,
+}
+
+/// One kept line, with the subheading and label it sits under
+struct Entry {
+    context: Vec<String>,
+    line: String,
+    /// Under a decisions heading or label
+    decision: bool,
+}
+
+fn sections(condensed: &str) -> Vec<Section> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut pending: Vec<String> = Vec::new();
+    let mut decision = false;
+
+    for line in condensed.lines().filter(|l| !l.trim().is_empty()) {
+        if line.starts_with("## ") {
+            sections.push(Section {
+                heading: line.to_string(),
+                entries: Vec::new(),
+            });
+            pending.clear();
+            decision = line.to_lowercase().contains("decision");
+        } else if line.starts_with('#') || is_label(line) {
+            // Only the innermost heading and label still apply
+            if line.starts_with('#') {
+                pending.clear();
+            } else {
+                pending.retain(|p| p.starts_with('#'));
+            }
+            decision = line.to_lowercase().contains("decision");
+            pending.push(line.to_string());
+        } else if let Some(section) = sections.last_mut() {
+            section.entries.push(Entry {
+                context: pending.clone(),
+                line: line.to_string(),
+                decision,
+            });
+        }
+    }
+
+    sections
+}
+
+/// A bold line that introduces the lines below it, e.g. "**Key components:**"
+fn is_label(line: &str) -> bool {
+    line.starts_with("**") && (line.ends_with(":**") || line.ends_with("**:"))
+}
+
+/// Pick entries round-robin across sections, decisions first within each section,
+/// until the next entry would exceed the budget
+fn select(sections: &[Section], budget: usize) -> Vec<Vec<bool>> {
+    let order: Vec<Vec<usize>> = sections
+        .iter()
+        .map(|s| {
+            let mut indices: Vec<usize> = (0..s.entries.len()).collect();
+            indices.sort_by_key(|&i| !s.entries[i].decision);
+            indices
+        })
+        .collect();
+
+    let mut chosen: Vec<Vec<bool>> = sections
+        .iter()
+        .map(|s| vec![false; s.entries.len()])
+        .collect();
+    let mut next = vec![0; sections.len()];
+    let mut full = vec![false; sections.len()];
+    let mut used = 0;
+
+    while full.iter().any(|f| !f) {
+        for (i, section) in sections.iter().enumerate() {
+            if full[i] {
+                continue;
+            }
+            let Some(&index) = order[i].get(next[i]) else {
+                full[i] = true;
+                continue;
+            };
+
+            let entry = &section.entries[index];
+            let context_shown = section
+                .entries
+                .iter()
+                .zip(&chosen[i])
+                .any(|(e, c)| *c && e.context == entry.context);
+            let mut cost = word_count(&entry.line);
+            if !context_shown {
+                cost += entry.context.iter().map(|c| word_count(c)).sum::<usize>();
+            }
+            if next[i] == 0 {
+                cost += word_count(&section.heading);
+            }
+
+            if used + cost > budget {
+                full[i] = true;
+            } else {
+                used += cost;
+                chosen[i][index] = true;
+                next[i] += 1;
+            }
+        }
+    }
+
+    chosen
+}
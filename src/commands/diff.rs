@@ -0,0 +1,142 @@
+use crate::config::{Config, ConfigError};
+use crate::embed::source::{Source, SourceError};
+use crate::fs::read_managed_block;
+use crate::git::find_repo_root;
+use crate::targets::{self, GenerateOptions, GeneratedFile, Origin, WriteMode};
+use console::style;
+use similar::{ChangeTag, TextDiff};
+use std::path::Path;
+use thiserror::Error;
+
+/// Lines of unchanged context around each change
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Error, Debug)]
+pub enum DiffError {
+    #[error("Not in a git repository")]
+    NotInGitRepo,
+    #[error("Invalid target: {0}. Valid: {valid}", valid = targets::valid_ids())]
+    InvalidTarget(String),
+    #[error(transparent)]
+    Source(#[from] SourceError),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Print a unified diff from each installed file to what the templates would install
+pub fn run(target: Option<Vec<String>>, source: Option<String>) -> Result<(), DiffError> {
+    let repo_root = find_repo_root()?.ok_or(DiffError::NotInGitRepo)?;
+    let config = Config::load_or_default(&repo_root)?;
+    let targets = targets::resolve(target.or(config.targets.clone()), &repo_root)
+        .map_err(DiffError::InvalidTarget)?;
+
+    let source = Source::parse(source.or(config.source.clone()).as_deref());
+    println!(
+        "{} Comparing installed files with {} templates",
+        style("→").blue(),
+        source.describe()
+    );
+
+    let options = GenerateOptions::from_config(&repo_root, &config)?;
+    let artifacts = source.load()?.select(
+        options.only.as_deref(),
+        options.skip.as_deref(),
+        &options.stack,
+    );
+    let files = targets::generate_with_origins(&targets, &artifacts, &options);
+
+    let compared: Vec<&(GeneratedFile, Origin)> = files
+        .iter()
+        .filter(|(f, _)| !targets::PROJECT_FILES.contains(&f.target_path.as_str()))
+        .collect();
+
+    let mut changed = 0;
+    let mut missing: Vec<(&str, Origin)> = Vec::new();
+    for (file, origin) in compared.iter().copied() {
+        let Some(installed) = installed(&repo_root, file) else {
+            missing.push((&file.target_path, *origin));
+            continue;
+        };
+        let expected = match file.mode {
+            WriteMode::Replace => file.content.clone(),
+            WriteMode::ManagedBlock { .. } => file.content.trim_end().to_string(),
+        };
+        if installed == expected {
+            continue;
+        }
+
+        changed += 1;
+        println!();
+        print_diff(&file.target_path, *origin, &installed, &expected);
+    }
+
+    if !missing.is_empty() {
+        println!();
+        println!("{} Not installed:", style("!").yellow());
+        for (path, origin) in &missing {
+            println!("  - {} ({})", path, origin.as_str());
+        }
+    }
+
+    println!();
+    if changed == 0 && missing.is_empty() {
+        println!("{} All files match", style("✓").green());
+    } else {
+        println!(
+            "{} {} changed, {} not installed, {} up to date",
+            style("→").blue(),
+            changed,
+            missing.len(),
+            compared.len() - changed - missing.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// The installed content ai-init owns: the whole file, or the managed block
+fn installed(repo_root: &Path, file: &GeneratedFile) -> Option<String> {
+    let path = repo_root.join(&file.target_path);
+    match file.mode {
+        WriteMode::Replace => std::fs::read_to_string(&path).ok(),
+        WriteMode::ManagedBlock { .. } => read_managed_block(&path),
+    }
+}
+
+/// Unified diff with `-` for installed lines and `+` for the lines ai-init would write
+fn print_diff(path: &str, origin: Origin, installed: &str, expected: &str) {
+    println!("{}", style(format!("--- {} (installed)", path)).bold());
+    println!(
+        "{}",
+        style(format!("+++ {} ({})", path, origin.as_str())).bold()
+    );
+
+    let diff = TextDiff::from_lines(installed, expected);
+    for group in diff.grouped_ops(CONTEXT_LINES) {
+        let (first, last) = (&group[0], &group[group.len() - 1]);
+        println!(
+            "{}",
+            style(format!(
+                "@@ -{},{} +{},{} @@",
+                first.old_range().start + 1,
+                last.old_range().end - first.old_range().start,
+                first.new_range().start + 1,
+                last.new_range().end - first.new_range().start
+            ))
+            .cyan()
+        );
+
+        for op in &group {
+            for change in diff.iter_changes(op) {
+                let line = change.value().trim_end_matches('\n');
+                match change.tag() {
+                    ChangeTag::Delete => println!("{}", style(format!("-{}", line)).red()),
+                    ChangeTag::Insert => println!("{}", style(format!("+{}", line)).green()),
+                    ChangeTag::Equal => println!(" {}", line),
+                }
+            }
+        }
+    }
+}
@@ -1,52 +1,104 @@
-use crate::embed::{self, EmbeddedFile};
-use crate::fs::update_gitignore;
+use crate::commands::context::stats::{self, Thresholds};
+use crate::config::{Config, ConfigError, GitignoreMode};
+use crate::embed::source::{Source, SourceError};
+use crate::embed::Artifact;
+use crate::fs::{missing_gitignore_entries, update_gitignore, write_managed_block};
 use crate::git::find_repo_root;
+use crate::targets::{
+    self, Detection, GenerateOptions, GeneratedFile, Origin, Overrides, Target, WriteMode,
+};
 use console::style;
 use dialoguer::Confirm;
+use std::path::Path;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum DoctorError {
     #[error("Not in a git repository")]
     NotInGitRepo,
+    #[error("Invalid target: {0}. Valid: {valid}", valid = targets::valid_ids())]
+    InvalidTarget(String),
+    #[error(transparent)]
+    Source(#[from] SourceError),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     #[error("Dialog error: {0}")]
     Dialog(#[from] dialoguer::Error),
 }
 
-pub fn run(auto_fix: bool) -> Result<(), DoctorError> {
+pub fn run(
+    auto_fix: bool,
+    target: Option<Vec<String>>,
+    source: Option<String>,
+) -> Result<(), DoctorError> {
     let repo_root = find_repo_root()?.ok_or(DoctorError::NotInGitRepo)?;
+    let config = Config::load_or_default(&repo_root)?;
+    let targets = targets::resolve(target.or(config.targets.clone()), &repo_root)
+        .map_err(DoctorError::InvalidTarget)?;
 
     println!(
         "{} Checking repository at: {}",
         style("→").blue(),
         repo_root.display()
     );
+
+    let detected = targets::detect(&repo_root);
+    if !detected.is_empty() {
+        println!(
+            "{} Detected AI tools: {}",
+            style("→").blue(),
+            detected
+                .iter()
+                .map(|d| d.describe())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    let source = Source::parse(source.or(config.source.clone()).as_deref());
+    if !source.is_embedded() {
+        println!(
+            "{} Templates from: {}",
+            style("→").blue(),
+            source.describe()
+        );
+    }
     println!();
 
-    let files = embed::get_all_files();
-    let mut missing: Vec<&EmbeddedFile> = Vec::new();
+    let options = GenerateOptions::from_config(&repo_root, &config)?;
+    let artifacts = source.load()?.select(
+        options.only.as_deref(),
+        options.skip.as_deref(),
+        &options.stack,
+    );
+    let files = targets::generate_with_origins(&targets, &artifacts, &options);
+    report_overrides(&files);
+    let mut missing: Vec<&(GeneratedFile, Origin)> = Vec::new();
     let mut present = 0;
 
     // Check each file existence
-    for file in &files {
-        let target = repo_root.join(file.target_path);
-        if target.exists() {
+    for entry in &files {
+        if entry.0.is_present(&repo_root) {
             present += 1;
         } else {
-            missing.push(file);
+            missing.push(entry);
         }
     }
 
     // Check .gitignore entries
     let gitignore_path = repo_root.join(".gitignore");
-    let gitignore_needs_update = if gitignore_path.exists() {
-        let content = std::fs::read_to_string(&gitignore_path)?;
-        !content.contains(".cursor/") || !content.contains(".ai/")
-    } else {
-        false
+    let gitignore_entries = targets::gitignore_entries(&targets);
+    let gitignore_missing = match config.gitignore {
+        GitignoreMode::Skip => Vec::new(),
+        _ if gitignore_path.exists() => {
+            let content = std::fs::read_to_string(&gitignore_path)?;
+            missing_gitignore_entries(&content, &gitignore_entries)
+        }
+        GitignoreMode::Create => gitignore_entries.clone(),
+        GitignoreMode::Update => Vec::new(),
     };
+    let gitignore_needs_update = !gitignore_missing.is_empty();
 
     // Report results
     println!(
@@ -56,6 +108,9 @@ pub fn run(auto_fix: bool) -> Result<(), DoctorError> {
         files.len()
     );
 
+    report_drift(&repo_root, &detected, &artifacts, &options);
+    report_context_cost(&repo_root, &targets, &artifacts, &options);
+
     if missing.is_empty() && !gitignore_needs_update {
         println!();
         println!("{} All checks passed!", style("✓").green().bold());
@@ -66,8 +121,11 @@ pub fn run(auto_fix: bool) -> Result<(), DoctorError> {
     if !missing.is_empty() {
         println!();
         println!("{} Missing files:", style("✗").red());
-        for file in &missing {
-            println!("  - {}", file.target_path);
+        for (file, origin) in &missing {
+            match origin {
+                Origin::Template => println!("  - {}", file.target_path),
+                _ => println!("  - {} ({})", file.target_path, origin.as_str()),
+            }
         }
     }
 
@@ -75,8 +133,9 @@ pub fn run(auto_fix: bool) -> Result<(), DoctorError> {
     if gitignore_needs_update {
         println!();
         println!(
-            "{} .gitignore missing entries for .cursor/ and/or .ai/",
-            style("✗").red()
+            "{} .gitignore missing entries for {}",
+            style("✗").red(),
+            gitignore_missing.join(" and ")
         );
     }
 
@@ -95,18 +154,28 @@ pub fn run(auto_fix: bool) -> Result<(), DoctorError> {
 
         if should_fix {
             // Create missing files
-            for file in &missing {
-                let target = repo_root.join(file.target_path);
-                if let Some(parent) = target.parent() {
-                    std::fs::create_dir_all(parent)?;
+            for (file, _) in &missing {
+                let target = repo_root.join(&file.target_path);
+                match file.mode {
+                    WriteMode::Replace => {
+                        if let Some(parent) = target.parent() {
+                            std::fs::create_dir_all(parent)?;
+                        }
+                        std::fs::write(&target, &file.content)?;
+                    }
+                    WriteMode::ManagedBlock { header } => {
+                        write_managed_block(&target, header, &file.content)?;
+                    }
                 }
-                std::fs::write(&target, file.content)?;
                 println!("  {} Created {}", style("✓").green(), file.target_path);
             }
 
             // Fix gitignore
             if gitignore_needs_update {
-                update_gitignore(&gitignore_path)?;
+                if !gitignore_path.exists() {
+                    std::fs::write(&gitignore_path, "")?;
+                }
+                update_gitignore(&gitignore_path, &gitignore_entries)?;
                 println!("  {} Updated .gitignore", style("✓").green());
             }
 
@@ -117,3 +186,143 @@ pub fn run(auto_fix: bool) -> Result<(), DoctorError> {
 
     Ok(())
 }
+
+/// Warn when detected tools have fallen out of step with each other:
+/// one tool's files match the current templates while another's are stale,
+/// or a tool is in use without any ai-init files.
+fn report_drift(
+    repo_root: &Path,
+    detected: &[Detection],
+    artifacts: &[Artifact],
+    options: &GenerateOptions,
+) {
+    // Drift is a cross-tool check
+    if detected.len() < 2 {
+        return;
+    }
+
+    let artifacts: Vec<&Artifact> = artifacts.iter().collect();
+
+    let mut in_sync: Vec<&str> = Vec::new();
+    let mut drifted: Vec<(&str, Vec<String>)> = Vec::new();
+    let mut unmanaged: Vec<&Detection> = Vec::new();
+
+    for detection in detected {
+        // Compare against the effective files, leaving out extras that belong to no tool
+        let files: Vec<GeneratedFile> = options
+            .overrides
+            .apply(
+                detection.target.render(&artifacts, options),
+                std::slice::from_ref(&detection.target),
+                options,
+            )
+            .into_iter()
+            .filter(|(_, origin)| *origin != Origin::Extra)
+            .map(|(file, _)| file)
+            .collect();
+        let installed: Vec<&GeneratedFile> =
+            files.iter().filter(|f| f.is_present(repo_root)).collect();
+
+        if installed.is_empty() {
+            unmanaged.push(detection);
+            continue;
+        }
+
+        let stale: Vec<String> = installed
+            .iter()
+            .filter(|f| !f.is_current(repo_root))
+            .map(|f| f.target_path.clone())
+            .collect();
+        if stale.is_empty() {
+            in_sync.push(detection.target.id());
+        } else {
+            drifted.push((detection.target.id(), stale));
+        }
+    }
+
+    if in_sync.is_empty() && drifted.is_empty() {
+        return;
+    }
+
+    for (id, stale) in &drifted {
+        println!();
+        if in_sync.is_empty() {
+            println!(
+                "{} {}: {} files differ from the current templates",
+                style("!").yellow(),
+                id,
+                stale.len()
+            );
+        } else {
+            println!(
+                "{} {}: {} files differ from the current templates while {} is up to date",
+                style("!").yellow(),
+                id,
+                stale.len(),
+                in_sync.join(", ")
+            );
+        }
+        for path in stale {
+            println!("  - {}", path);
+        }
+        println!("  Refresh with: ai-init init --target {} --force", id);
+    }
+
+    for detection in unmanaged {
+        println!();
+        println!(
+            "{} {} is in use but has no ai-init files. Install with: ai-init init --target {}",
+            style("!").yellow(),
+            detection.describe(),
+            detection.target.id()
+        );
+    }
+}
+
+/// Note files that come from the override directory rather than the templates
+fn report_overrides(files: &[(GeneratedFile, Origin)]) {
+    let count = |origin: Origin| files.iter().filter(|(_, o)| *o == origin).count();
+    let (shadowing, extra) = (count(Origin::Override), count(Origin::Extra));
+    if shadowing + extra == 0 {
+        return;
+    }
+
+    let dir = Overrides::dir()
+        .map(|d| d.display().to_string())
+        .unwrap_or_default();
+    println!(
+        "{} Overrides from {} (shadowed: {}, extra: {})",
+        style("→").blue(),
+        dir,
+        shadowing,
+        extra
+    );
+    for (file, origin) in files.iter().filter(|(_, o)| *o != Origin::Template) {
+        println!("  - {} ({})", file.target_path, origin.as_str());
+    }
+    println!();
+}
+
+/// Warn when installed files cost more tokens than the default thresholds
+fn report_context_cost(
+    repo_root: &Path,
+    targets: &[Box<dyn Target>],
+    artifacts: &[Artifact],
+    options: &GenerateOptions,
+) {
+    let warnings =
+        stats::collect(repo_root, targets, artifacts, options).warnings(&Thresholds::default());
+    if warnings.is_empty() {
+        return;
+    }
+
+    println!();
+    println!(
+        "{} Context is above the token thresholds:",
+        style("!").yellow()
+    );
+    for warning in &warnings {
+        println!("  - {}", warning);
+    }
+    println!("  Details with: ai-init context stats");
+}
@@ -0,0 +1,167 @@
+use super::checks::{self, Rule, Severity};
+use super::HookInput;
+use crate::stack::Language;
+use serde_json::{json, Value};
+use std::io::Write;
+use std::path::Path;
+
+/// Audit log of edits, relative to repo root
+pub const EDIT_LOG: &str = ".ai/edit-log.txt";
+/// Previous audit log, kept after rotation
+pub const EDIT_LOG_ROTATED: &str = ".ai/edit-log.1.txt";
+/// Size at which the audit log is rotated
+const EDIT_LOG_MAX_BYTES: u64 = 256 * 1024;
+
+/// Log the edit and run the post-edit checks for the edited file's language
+pub fn run(
+    input: &HookInput,
+    repo_root: &Path,
+    overrides: &[(String, Option<Severity>)],
+    languages: &[Language],
+) -> std::io::Result<Value> {
+    let Some(file_path) = input.file_path.as_deref() else {
+        return Ok(json!({}));
+    };
+
+    log_edit(repo_root, input, file_path)?;
+
+    let path = repo_root.join(file_path);
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    if !checks::CODE_EXTENSIONS.contains(&extension.as_str()) {
+        return Ok(json!({}));
+    }
+
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return Ok(json!({}));
+    };
+
+    let rules = configured_rules(overrides, languages);
+    let mut findings = checks::check(&rules, &extension, &content);
+    if findings.is_empty() {
+        return Ok(json!({}));
+    }
+    findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
+
+    Ok(json!({
+        "message": format!(
+            "Validation found {} potential issue(s) in {}",
+            findings.len(),
+            file_path
+        ),
+        "warnings": findings.iter().map(|f| f.describe()).collect::<Vec<_>>(),
+    }))
+}
+
+/// Built-in rules for the selected languages with `--rule` overrides applied;
+/// `None` turns a rule off
+fn configured_rules(overrides: &[(String, Option<Severity>)], languages: &[Language]) -> Vec<Rule> {
+    checks::rules()
+        .into_iter()
+        .filter(|rule| languages.is_empty() || rule.applies_to_any(languages))
+        .filter_map(|mut rule| {
+            match overrides.iter().rev().find(|(id, _)| id == rule.id) {
+                Some((_, Some(severity))) => rule.severity = *severity,
+                Some((_, None)) => return None,
+                None => {}
+            }
+            Some(rule)
+        })
+        .collect()
+}
+
+/// Append the edit to the audit log, tagged with the conversation it belongs to.
+/// The log is rotated once it grows past `EDIT_LOG_MAX_BYTES`.
+fn log_edit(repo_root: &Path, input: &HookInput, file_path: &str) -> std::io::Result<()> {
+    if !repo_root.join(".ai").is_dir() {
+        return Ok(());
+    }
+
+    let log_path = repo_root.join(EDIT_LOG);
+    if std::fs::metadata(&log_path).is_ok_and(|m| m.len() > EDIT_LOG_MAX_BYTES) {
+        std::fs::rename(&log_path, repo_root.join(EDIT_LOG_ROTATED))?;
+    }
+
+    let mut log = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)?;
+    writeln!(
+        log,
+        "[{}] [{}] Edited: {} ({} change(s))",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+        input.conversation_id.as_deref().unwrap_or("-"),
+        file_path,
+        input.edits.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(file_path: &str) -> HookInput {
+        HookInput {
+            file_path: Some(file_path.to_string()),
+            ..HookInput::default()
+        }
+    }
+
+    #[test]
+    fn languages_limit_rules_to_their_extensions() {
+        let rules = configured_rules(&[], &[Language::Rust]);
+        let ids: Vec<&str> = rules.iter().map(|r| r.id).collect();
+        assert!(ids.contains(&"rust-unwrap"));
+        assert!(ids.contains(&"todo-comment"));
+        assert!(!ids.contains(&"python-print"));
+    }
+
+    #[test]
+    fn overrides_change_severity_or_turn_rules_off() {
+        let overrides = [
+            ("rust-unwrap".to_string(), Some(Severity::Error)),
+            ("rust-dbg".to_string(), None),
+        ];
+        let rules = configured_rules(&overrides, &[]);
+        let unwrap = rules.iter().find(|r| r.id == "rust-unwrap").unwrap();
+        assert_eq!(unwrap.severity, Severity::Error);
+        assert!(!rules.iter().any(|r| r.id == "rust-dbg"));
+    }
+
+    #[test]
+    fn reports_findings_most_severe_first() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("lib.rs"),
+            "// TODO: tidy up\nfn f(y: Option<u8>) {\n    let x = y.unwrap();\n    dbg!(x);\n}\n",
+        )
+        .unwrap();
+
+        let overrides = [("rust-dbg".to_string(), None)];
+        let output = run(&edit("lib.rs"), dir.path(), &overrides, &[]).unwrap();
+        let warnings: Vec<&str> = output["warnings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|w| w.as_str())
+            .collect();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].starts_with("[warning] rust-unwrap (line 3)"));
+        assert!(warnings[1].starts_with("[info] todo-comment (line 1)"));
+    }
+
+    #[test]
+    fn skips_clean_and_non_code_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("lib.rs"), "fn f() {}\n").unwrap();
+        std::fs::write(dir.path().join("notes.md"), "TODO: write notes\n").unwrap();
+
+        for file in ["lib.rs", "notes.md", "missing.rs"] {
+            let output = run(&edit(file), dir.path(), &[], &[]).unwrap();
+            assert_eq!(output, json!({}), "{}", file);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crate::stack::Language;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// How strongly a finding is reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "info" => Some(Self::Info),
+            "warning" | "warn" => Some(Self::Warning),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// How a rule inspects a file
+enum Check {
+    /// Any line matching the regex
+    Pattern(&'static str),
+    /// Five or more consecutive comment lines, a heuristic for commented-out code
+    CommentBlock,
+}
+
+/// A post-edit check
+pub struct Rule {
+    pub id: &'static str,
+    /// File extensions the rule applies to; empty means every code file
+    extensions: &'static [&'static str],
+    check: Check,
+    message: &'static str,
+    pub severity: Severity,
+}
+
+impl Rule {
+    /// Whether the rule is generic or checks files of one of `languages`
+    pub fn applies_to_any(&self, languages: &[Language]) -> bool {
+        self.extensions.is_empty()
+            || languages.iter().any(|l| {
+                l.extensions()
+                    .iter()
+                    .any(|ext| self.extensions.contains(ext))
+            })
+    }
+}
+
+const JS: &[&str] = &["js", "jsx", "mjs", "cjs", "ts", "tsx"];
+const TS: &[&str] = &["ts", "tsx"];
+
+/// Extensions treated as code; other files are logged but not checked
+pub const CODE_EXTENSIONS: &[&str] = &[
+    "ts", "tsx", "js", "jsx", "mjs", "cjs", "py", "rs", "go", "java", "kt", "cs", "cpp", "cc", "c",
+    "h", "hpp", "rb", "php", "swift",
+];
+
+/// Built-in rules, in reporting order
+pub fn rules() -> Vec<Rule> {
+    vec![
+        Rule {
+            id: "js-console-log",
+            extensions: JS,
+            check: Check::Pattern(r"\bconsole\.(log|debug)\("),
+            message: "console.log found - consider using a logger",
+            severity: Severity::Warning,
+        },
+        Rule {
+            id: "ts-any",
+            extensions: TS,
+            check: Check::Pattern(r":\s*any\b"),
+            message: "': any' type found - consider explicit typing",
+            severity: Severity::Warning,
+        },
+        Rule {
+            id: "rust-unwrap",
+            extensions: &["rs"],
+            check: Check::Pattern(r"\.unwrap\(\)|\.expect\(\s*\)"),
+            message: ".unwrap() found - propagate the error or use expect() with a reason",
+            severity: Severity::Warning,
+        },
+        Rule {
+            id: "rust-dbg",
+            extensions: &["rs"],
+            check: Check::Pattern(r"\bdbg!\("),
+            message: "dbg! found - remove debugging output",
+            severity: Severity::Warning,
+        },
+        Rule {
+            id: "rust-todo",
+            extensions: &["rs"],
+            check: Check::Pattern(r"\b(todo|unimplemented)!\("),
+            message: "todo!/unimplemented! found - AI may have left a stub",
+            severity: Severity::Warning,
+        },
+        Rule {
+            id: "python-print",
+            extensions: &["py"],
+            check: Check::Pattern(r"^\s*print\s*\("),
+            message: "print() found - consider using logging",
+            severity: Severity::Warning,
+        },
+        Rule {
+            id: "python-breakpoint",
+            extensions: &["py"],
+            check: Check::Pattern(r"\b(breakpoint\(\)|pdb\.set_trace\(\))"),
+            message: "breakpoint found - remove debugging code",
+            severity: Severity::Error,
+        },
+        Rule {
+            id: "go-println",
+            extensions: &["go"],
+            check: Check::Pattern(r"\bfmt\.Print(ln|f)?\("),
+            message: "fmt.Println found - consider using a logger",
+            severity: Severity::Warning,
+        },
+        Rule {
+            id: "java-system-out",
+            extensions: &["java", "kt"],
+            check: Check::Pattern(r"\bSystem\.(out|err)\.print"),
+            message: "System.out.println found - consider using a logger",
+            severity: Severity::Warning,
+        },
+        Rule {
+            id: "todo-comment",
+            extensions: &[],
+            check: Check::Pattern(r"\b(TODO|FIXME|XXX|HACK)\b"),
+            message: "TODO/FIXME comment found - AI may have left placeholder",
+            severity: Severity::Info,
+        },
+        Rule {
+            id: "commented-code",
+            extensions: &[],
+            check: Check::CommentBlock,
+            message: "Large commented block found - may be dead code",
+            severity: Severity::Info,
+        },
+    ]
+}
+
+/// Comma-separated list of rule ids, for error messages
+pub fn valid_ids() -> String {
+    rules().iter().map(|r| r.id).collect::<Vec<_>>().join(", ")
+}
+
+/// A rule that matched, with the first matching line and the number of matches
+pub struct Finding {
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: &'static str,
+    pub line: usize,
+    pub count: usize,
+}
+
+impl Finding {
+    pub fn describe(&self) -> String {
+        let more = match self.count {
+            1 => String::new(),
+            n => format!(", {} more", n - 1),
+        };
+        format!(
+            "[{}] {} (line {}{}): {}",
+            self.severity.as_str(),
+            self.rule,
+            self.line,
+            more,
+            self.message
+        )
+    }
+}
+
+/// Apply the rules for `extension` to `content`
+pub fn check(rules: &[Rule], extension: &str, content: &str) -> Vec<Finding> {
+    rules
+        .iter()
+        .filter(|r| r.extensions.is_empty() || r.extensions.contains(&extension))
+        .filter_map(|rule| {
+            let lines = matching_lines(&rule.check, content);
+            let line = *lines.first()?;
+            Some(Finding {
+                rule: rule.id,
+                severity: rule.severity,
+                message: rule.message,
+                line,
+                count: lines.len(),
+            })
+        })
+        .collect()
+}
+
+/// A line that is only a comment
+static COMMENT_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*(//|/\*|\*|#[^\[!])\s*\S").expect("comment pattern is valid")
+});
+
+/// 1-based numbers of the lines where the check matches
+fn matching_lines(check: &Check, content: &str) -> Vec<usize> {
+    match check {
+        Check::Pattern(pattern) => {
+            let re = match Regex::new(pattern) {
+                Ok(re) => re,
+                Err(e) => {
+                    eprintln!(
+                        "ai-init hook: skipping invalid check pattern {}: {}",
+                        pattern, e
+                    );
+                    return Vec::new();
+                }
+            };
+            content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| re.is_match(line))
+                .map(|(i, _)| i + 1)
+                .collect()
+        }
+        Check::CommentBlock => {
+            let mut blocks = Vec::new();
+            let mut streak = 0;
+            for (i, line) in content.lines().enumerate() {
+                if COMMENT_LINE.is_match(line) {
+                    streak += 1;
+                    if streak == 5 {
+                        blocks.push(i + 1 - 4);
+                    }
+                } else {
+                    streak = 0;
+                }
+            }
+            blocks
+        }
+    }
+}
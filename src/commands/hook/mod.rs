@@ -0,0 +1,223 @@
+mod after_file_edit;
+mod checks;
+mod session_start;
+mod stop;
+
+pub use stop::LEARNINGS_PENDING;
+
+use crate::git::find_repo_root;
+use crate::stack::Language;
+use checks::Severity;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::io::Read;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum HookError {
+    #[error("Unknown hook event: {0}. Valid: session-start, after-file-edit, stop")]
+    UnknownEvent(String),
+    #[error("Invalid rule setting: {0}. Expected <id>=<off|info|warning|error> with id one of: {ids}", ids = checks::valid_ids())]
+    InvalidRule(String),
+    #[error(
+        "Invalid language: {0}. Valid: rust, typescript, javascript, python, go, java, csharp"
+    )]
+    InvalidLanguage(String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Hook events handled by `ai-init hook`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    SessionStart,
+    AfterFileEdit,
+    Stop,
+}
+
+impl HookEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SessionStart => "session-start",
+            Self::AfterFileEdit => "after-file-edit",
+            Self::Stop => "stop",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "session-start" | "sessionstart" => Some(Self::SessionStart),
+            "after-file-edit" | "afterfileedit" => Some(Self::AfterFileEdit),
+            "stop" => Some(Self::Stop),
+            _ => None,
+        }
+    }
+}
+
+/// Default token budget for context injected at session start
+pub const DEFAULT_TOKEN_BUDGET: usize = 2000;
+
+/// Settings passed on the hook command line in hooks.json
+pub struct HookOptions {
+    /// Token budget for session-start context
+    pub token_budget: usize,
+    /// Files under `.ai/` to inject at session start, in priority order
+    pub context: Vec<String>,
+    /// Post-edit rule severity overrides as `<id>=<severity>`; `off` disables a rule
+    pub rules: Vec<String>,
+    /// Languages whose post-edit rules run; empty runs them all
+    pub languages: Vec<String>,
+}
+
+impl HookOptions {
+    /// Parsed `--rule` overrides; `None` severity turns the rule off
+    fn rule_overrides(&self) -> Result<Vec<(String, Option<Severity>)>, HookError> {
+        self.rules
+            .iter()
+            .map(|setting| {
+                let invalid = || HookError::InvalidRule(setting.clone());
+                let (id, severity) = setting.split_once('=').ok_or_else(invalid)?;
+                let id = id.trim().to_lowercase();
+                if !checks::rules().iter().any(|r| r.id == id) {
+                    return Err(invalid());
+                }
+                let severity = match severity.trim() {
+                    "off" => None,
+                    s => Some(Severity::from_str(s).ok_or_else(invalid)?),
+                };
+                Ok((id, severity))
+            })
+            .collect()
+    }
+}
+
+/// Fields Cursor sends on stdin; each event uses a subset
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct HookInput {
+    pub conversation_id: Option<String>,
+    pub workspace_roots: Vec<String>,
+    pub file_path: Option<String>,
+    pub edits: Vec<Value>,
+    pub status: Option<String>,
+    pub loop_count: u32,
+}
+
+impl HookInput {
+    /// Repository the hook runs for: the first workspace root, else the enclosing git repo
+    fn repo_root(&self) -> std::io::Result<PathBuf> {
+        if let Some(root) = self.workspace_roots.first() {
+            return Ok(PathBuf::from(root));
+        }
+        match find_repo_root()? {
+            Some(root) => Ok(root),
+            None => std::env::current_dir(),
+        }
+    }
+}
+
+/// Run a Cursor hook natively: read the hook input JSON from stdin
+/// and write the output JSON to stdout
+pub fn run(event: String, options: HookOptions) -> Result<(), HookError> {
+    let event = HookEvent::from_str(&event).ok_or(HookError::UnknownEvent(event))?;
+    let rule_overrides = options.rule_overrides()?;
+    let languages = options
+        .languages
+        .iter()
+        .map(|l| Language::from_str(l).ok_or_else(|| HookError::InvalidLanguage(l.clone())))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut raw = String::new();
+    std::io::stdin().read_to_string(&mut raw)?;
+    let raw = raw.trim_start_matches('\u{feff}').trim();
+    // Malformed input must not block the agent either: log it and carry on without fields
+    let input: HookInput = if raw.is_empty() {
+        HookInput::default()
+    } else {
+        serde_json::from_str(raw).unwrap_or_else(|e| {
+            eprintln!("ai-init hook {}: invalid input JSON: {}", event.as_str(), e);
+            HookInput::default()
+        })
+    };
+    let repo_root = input.repo_root()?;
+
+    let output = match event {
+        HookEvent::SessionStart => session_start::run(&input, &repo_root, &options),
+        HookEvent::AfterFileEdit => {
+            after_file_edit::run(&input, &repo_root, &rule_overrides, &languages)
+        }
+        HookEvent::Stop => stop::run(&input, &repo_root),
+    };
+
+    // A failing hook must not block the agent: report and answer with an empty object
+    let output = output.unwrap_or_else(|e| {
+        eprintln!("ai-init hook {}: {}", event.as_str(), e);
+        json!({})
+    });
+    println!("{}", serde_json::to_string(&output)?);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(rules: &[&str]) -> HookOptions {
+        HookOptions {
+            token_budget: DEFAULT_TOKEN_BUDGET,
+            context: Vec::new(),
+            rules: rules.iter().map(|r| r.to_string()).collect(),
+            languages: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn rule_overrides_parse_severities_and_off() {
+        let overrides = options(&["rust-unwrap=error", " TODO-Comment = off "])
+            .rule_overrides()
+            .unwrap();
+        assert_eq!(
+            overrides,
+            vec![
+                ("rust-unwrap".to_string(), Some(Severity::Error)),
+                ("todo-comment".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn rule_overrides_reject_unknown_rules_and_severities() {
+        for setting in ["no-such-rule=warning", "rust-unwrap=loud", "rust-unwrap"] {
+            let result = options(&[setting]).rule_overrides();
+            assert!(
+                matches!(&result, Err(HookError::InvalidRule(s)) if s == setting),
+                "{} was accepted",
+                setting
+            );
+        }
+    }
+
+    #[test]
+    fn hook_event_from_str_accepts_names_and_aliases() {
+        for event in [
+            HookEvent::SessionStart,
+            HookEvent::AfterFileEdit,
+            HookEvent::Stop,
+        ] {
+            assert_eq!(HookEvent::from_str(event.as_str()), Some(event));
+        }
+        assert_eq!(
+            HookEvent::from_str("sessionStart"),
+            Some(HookEvent::SessionStart)
+        );
+        assert_eq!(
+            HookEvent::from_str("AfterFileEdit"),
+            Some(HookEvent::AfterFileEdit)
+        );
+        assert_eq!(HookEvent::from_str("before-submit"), None);
+    }
+}
@@ -0,0 +1,284 @@
+use super::{HookInput, HookOptions};
+use crate::embed::frontmatter;
+use crate::embed::markdown::{condense, estimate_tokens};
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// Files injected when `--context` isn't given. The full context is the fallback
+/// for repos that haven't written a summary yet.
+const DEFAULT_CONTEXT: &str = "ai-summary.md";
+const FALLBACK_CONTEXT: &str = "ai-context.md";
+
+/// Between injected sections
+const SEPARATOR: &str = "\n\n---\n\n";
+
+/// Active workflow documents and the status they start in
+const ACTIVE_DOCS: &[(&str, &str, &str)] = &[
+    ("active-research.md", "Active research", "research"),
+    ("active-plan.md", "Active plan", "planning"),
+];
+
+/// Inject the chosen context files and the state of any active research or plan,
+/// staying within the token budget
+pub fn run(_input: &HookInput, repo_root: &Path, options: &HookOptions) -> std::io::Result<Value> {
+    let ai_dir = repo_root.join(".ai");
+
+    let state = workflow_state(&ai_dir);
+    // The state and the pointer to the full context close every injection
+    let footer = "For full patterns and constraints, reference `.ai/ai-context.md`.";
+    let mut remaining = options.token_budget.saturating_sub(
+        estimate_tokens(&state) + estimate_tokens(SEPARATOR) + estimate_tokens(footer),
+    );
+    let mut sections: Vec<String> = Vec::new();
+
+    for name in context_files(&ai_dir, &options.context) {
+        let Some(body) = read_body(&ai_dir.join(&name)) else {
+            continue;
+        };
+
+        // Each section brings its heading and a separator
+        let heading = format!("# Project Context (from .ai/{})\n\n", name);
+        let budget =
+            remaining.saturating_sub(estimate_tokens(&heading) + estimate_tokens(SEPARATOR));
+        let text = fit(&body, budget, &name);
+        if budget == 0 || estimate_tokens(&text) > budget {
+            break;
+        }
+        remaining = budget - estimate_tokens(&text);
+        sections.push(format!("{}{}", heading, text));
+    }
+
+    if sections.is_empty() {
+        return Ok(json!({
+            "additional_context": format!(
+                "No .ai/ai-summary.md found. Run `ai-init init` to set up AI workflow templates.\n\n{}",
+                state
+            )
+        }));
+    }
+
+    sections.push(state);
+    sections.push(footer.to_string());
+
+    Ok(json!({ "additional_context": sections.join(SEPARATOR) }))
+}
+
+/// Context files to inject, relative to `.ai/`
+fn context_files(ai_dir: &Path, requested: &[String]) -> Vec<String> {
+    if !requested.is_empty() {
+        return requested.to_vec();
+    }
+    if ai_dir.join(DEFAULT_CONTEXT).exists() {
+        vec![DEFAULT_CONTEXT.to_string()]
+    } else {
+        vec![FALLBACK_CONTEXT.to_string()]
+    }
+}
+
+/// File contents without BOM, CRLF line endings or frontmatter
+fn read_body(path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?.replace("\r\n", "\n");
+    let (_, body) = frontmatter::parse(&content);
+    let body = body.trim();
+    (!body.is_empty()).then(|| body.to_string())
+}
+
+/// The body if it fits in `budget` tokens, else its condensed outline,
+/// else as many leading paragraphs as fit
+fn fit(body: &str, budget: usize, name: &str) -> String {
+    if estimate_tokens(body) <= budget {
+        return body.to_string();
+    }
+
+    let note = format!(
+        "_Shortened to fit the token budget; read `.ai/{}` for the rest._",
+        name
+    );
+    let budget = budget.saturating_sub(estimate_tokens(&note));
+
+    // Prose without headings or lists condenses to nothing, so fall through to paragraphs
+    let condensed = condense(body);
+    // The blank line before the note costs one more token
+    if !condensed.trim().is_empty() && estimate_tokens(&condensed) < budget {
+        return format!("{}\n\n{}", condensed.trim_end(), note);
+    }
+
+    let mut kept: Vec<&str> = Vec::new();
+    let mut used = 0;
+    for paragraph in body.split("\n\n") {
+        // Counting the blank line that follows each paragraph
+        used += estimate_tokens(paragraph) + 1;
+        if used > budget {
+            break;
+        }
+        kept.push(paragraph);
+    }
+    kept.push(&note);
+    kept.join("\n\n")
+}
+
+/// Describe the active research and plan documents from their frontmatter
+fn workflow_state(ai_dir: &Path) -> String {
+    let mut state = String::from("## Workflow State (auto-detected)\n\n");
+    let mut active = false;
+    let mut plan_status = None;
+
+    for (file, label, default_status) in ACTIVE_DOCS {
+        let Ok(content) = std::fs::read_to_string(ai_dir.join(file)) else {
+            continue;
+        };
+        let (fm, _) = frontmatter::parse(&content);
+        let field = |key: &str| {
+            fm.as_ref()
+                .and_then(|f| f.get_str(key))
+                .filter(|v| !v.starts_with('['))
+                .map(str::to_string)
+        };
+        let task = field("task").unwrap_or_else(|| "unknown".to_string());
+        let status = field("status").unwrap_or_else(|| default_status.to_string());
+
+        active = true;
+        state.push_str(&format!(
+            "- **{}**: `.ai/{}` (task: {}, status: {})\n",
+            label, file, task, status
+        ));
+        if *file == "active-plan.md" {
+            plan_status = Some(status);
+        }
+    }
+
+    match plan_status.as_deref() {
+        Some("planning") => {
+            state.push_str("\nTip: Run `/implement` when ready to execute this plan.\n")
+        }
+        Some("implementing") => {
+            state.push_str("\nTip: Implementation in progress. Follow the plan step by step.\n")
+        }
+        _ if !active => state
+            .push_str("No active research or plan. Use `/research [task]` to start a new task.\n"),
+        _ => {}
+    }
+
+    state.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(token_budget: usize) -> HookOptions {
+        HookOptions {
+            token_budget,
+            context: Vec::new(),
+            rules: Vec::new(),
+            languages: Vec::new(),
+        }
+    }
+
+    fn context(output: &Value) -> &str {
+        output["additional_context"].as_str().unwrap()
+    }
+
+    #[test]
+    fn fit_keeps_a_body_within_budget() {
+        let body = "# Title\n\nShort body.";
+        assert_eq!(fit(body, 100, "ai-summary.md"), body);
+    }
+
+    #[test]
+    fn fit_condenses_then_truncates() {
+        let prose = "word ".repeat(40);
+        let body = format!("## Rules\n\n- Keep it short. {}\n\n{}", prose, prose);
+
+        let condensed = fit(&body, 60, "ai-context.md");
+        assert!(condensed.starts_with("## Rules\n\n- Keep it short.\n"));
+        assert!(!condensed.contains(&prose));
+        assert!(condensed.ends_with("read `.ai/ai-context.md` for the rest._"));
+
+        // Prose has no outline, so whole paragraphs are kept instead
+        let paragraphs = format!("First paragraph.\n\n{}\n\n{}", prose, prose);
+        let truncated = fit(&paragraphs, 80, "ai-context.md");
+        assert!(truncated.starts_with("First paragraph.\n\n"));
+        assert!(estimate_tokens(&truncated) <= 80);
+        assert!(truncated.contains("_Shortened to fit the token budget"));
+    }
+
+    #[test]
+    fn injects_the_summary_within_the_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let ai = dir.path().join(".ai");
+        std::fs::create_dir(&ai).unwrap();
+        std::fs::write(ai.join("ai-context.md"), "# Context\n\nFull context.").unwrap();
+        std::fs::write(
+            ai.join("ai-summary.md"),
+            format!("# Summary\n\n{}", "Summary paragraph.\n\n".repeat(100)),
+        )
+        .unwrap();
+
+        let output = run(&HookInput::default(), dir.path(), &options(300)).unwrap();
+        let text = context(&output);
+        assert!(text.starts_with("# Project Context (from .ai/ai-summary.md)"));
+        assert!(!text.contains("Full context."));
+        assert!(text.contains("_Shortened to fit the token budget"));
+        assert!(text.contains("No active research or plan"));
+        assert!(estimate_tokens(text) < estimate_tokens(&"Summary paragraph.\n\n".repeat(100)));
+    }
+
+    #[test]
+    fn falls_back_to_the_full_context_and_reports_a_missing_one() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = run(&HookInput::default(), dir.path(), &options(2000)).unwrap();
+        assert!(context(&output).starts_with("No .ai/ai-summary.md found"));
+
+        let ai = dir.path().join(".ai");
+        std::fs::create_dir(&ai).unwrap();
+        std::fs::write(ai.join("ai-context.md"), "# Context\n\nFull context.").unwrap();
+        let output = run(&HookInput::default(), dir.path(), &options(2000)).unwrap();
+        assert!(context(&output).contains("Full context."));
+    }
+
+    #[test]
+    fn stays_within_the_token_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let ai = dir.path().join(".ai");
+        std::fs::create_dir(&ai).unwrap();
+        let outline = "## Rules\n\n- Keep it short. More detail here.\n\n".repeat(30);
+        std::fs::write(
+            ai.join("ai-summary.md"),
+            format!("# Summary\n\n{}", outline),
+        )
+        .unwrap();
+        std::fs::write(ai.join("notes.md"), "Plain paragraph.\n\n".repeat(200)).unwrap();
+
+        let mut options = options(0);
+        options.context = vec!["ai-summary.md".to_string(), "notes.md".to_string()];
+        for budget in [60, 100, 150, 250, 400, 800, 2000] {
+            options.token_budget = budget;
+            let output = run(&HookInput::default(), dir.path(), &options).unwrap();
+            let text = context(&output);
+            assert!(
+                estimate_tokens(text) <= budget,
+                "{} tokens over a budget of {}",
+                estimate_tokens(text),
+                budget
+            );
+        }
+    }
+
+    #[test]
+    fn reports_an_active_plan_without_a_context_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let ai = dir.path().join(".ai");
+        std::fs::create_dir(&ai).unwrap();
+        std::fs::write(
+            ai.join("active-plan.md"),
+            "---\ntask: add caching\nstatus: implementing\n---\n\n# Plan\n",
+        )
+        .unwrap();
+
+        let output = run(&HookInput::default(), dir.path(), &options(2000)).unwrap();
+        let text = context(&output);
+        assert!(text.starts_with("No .ai/ai-summary.md found"));
+        assert!(text.contains("(task: add caching, status: implementing)"));
+    }
+}
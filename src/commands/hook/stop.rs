@@ -0,0 +1,172 @@
+use super::after_file_edit::{EDIT_LOG, EDIT_LOG_ROTATED};
+use super::HookInput;
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// Edits after which a session is worth reviewing for learnings
+const EDIT_THRESHOLD: usize = 5;
+
+/// Prompts the user fills in; the first one marks the end of an entry's generated part
+const PROMPTS: &str = "\n- **Pattern**: \n- **Gotcha**: \n- **Decision**: \n";
+const PROMPTS_START: &str = "\n- **Pattern**:";
+
+/// Draft learnings awaiting review, relative to repo root
+pub const LEARNINGS_PENDING: &str = ".ai/learnings-pending.md";
+
+const PENDING_HEADER: &str = "# Pending Learnings\n\n\
+Drafts captured when agent sessions end. Fill in what was learned, \
+then run `ai-init learn review` to move what's worth keeping into `.ai/ai-context.md`.\n";
+
+/// After a substantial session, draft a learnings entry listing the files it edited
+pub fn run(input: &HookInput, repo_root: &Path) -> std::io::Result<Value> {
+    // Only after successful completion, and never on a follow-up loop
+    if input.status.as_deref() != Some("completed") || input.loop_count >= 1 {
+        return Ok(json!({}));
+    }
+    // Edits can only be attributed to a session through its conversation id
+    let Some(conversation) = input.conversation_id.as_deref() else {
+        return Ok(json!({}));
+    };
+
+    // The log may have been rotated mid-session, so read the older part first
+    let log: String = [EDIT_LOG_ROTATED, EDIT_LOG]
+        .iter()
+        .filter_map(|path| std::fs::read_to_string(repo_root.join(path)).ok())
+        .collect();
+    let (edits, files) = session_edits(&log, conversation);
+    if edits <= EDIT_THRESHOLD {
+        return Ok(json!({}));
+    }
+
+    write_draft(repo_root, conversation, edits, &files)?;
+
+    let mut listed: Vec<String> = files.iter().take(10).map(|f| format!("`{}`", f)).collect();
+    if files.len() > listed.len() {
+        listed.push(format!("and {} more", files.len() - listed.len()));
+    }
+
+    Ok(json!({
+        "followup_message": format!(
+            "This session made {} edits to {} files ({}). A draft entry is in {}: fill in the patterns, gotchas or decisions worth keeping, then run `ai-init learn review` to move them into .ai/ai-context.md.",
+            edits,
+            files.len(),
+            listed.join(", "),
+            LEARNINGS_PENDING
+        )
+    }))
+}
+
+/// Number of edits logged for `conversation` and the distinct files, in first-edit order
+fn session_edits(log: &str, conversation: &str) -> (usize, Vec<String>) {
+    let mut edits = 0;
+    let mut files: Vec<String> = Vec::new();
+
+    for entry in log.lines().filter_map(parse_entry) {
+        if entry.0 != conversation {
+            continue;
+        }
+        edits += 1;
+        if !files.iter().any(|f| f == entry.1) {
+            files.push(entry.1.to_string());
+        }
+    }
+
+    (edits, files)
+}
+
+/// Conversation id and file path of an edit-log line:
+/// `[<time>] [<conversation>] Edited: <path> (<n> change(s))`
+fn parse_entry(line: &str) -> Option<(&str, &str)> {
+    let rest = line.split_once("] [")?.1;
+    let (conversation, rest) = rest.split_once("] Edited: ")?;
+    let path = rest.rsplit_once(" (").map_or(rest, |(path, _)| path);
+    Some((conversation, path))
+}
+
+/// Add this conversation's entry to the pending learnings file,
+/// or refresh the file list of its earlier draft without touching what the user wrote
+fn write_draft(
+    repo_root: &Path,
+    conversation: &str,
+    edits: usize,
+    files: &[String],
+) -> std::io::Result<()> {
+    let path = repo_root.join(LEARNINGS_PENDING);
+    let existing = std::fs::read_to_string(&path).unwrap_or_else(|_| PENDING_HEADER.to_string());
+    let marker = format!("<!-- conversation: {} -->", conversation);
+
+    let mut summary = format!("\n\nEdited {} files ({} edits):\n\n", files.len(), edits);
+    for file in files {
+        summary.push_str(&format!("- `{}`\n", file));
+    }
+
+    // Only look for the prompts inside the latest entry for this conversation, up to the next entry
+    let splice = existing.rfind(&marker).and_then(|start| {
+        let start = start + marker.len();
+        let entry = existing[start..]
+            .find("\n## ")
+            .map_or(&existing[start..], |end| &existing[start..start + end]);
+        entry.find(PROMPTS_START).map(|end| (start, start + end))
+    });
+
+    let content = match splice {
+        Some((start, end)) => format!("{}{}{}", &existing[..start], summary, &existing[end..]),
+        // New conversation, or the user reworked the entry's prompts: add a fresh entry
+        None => format!(
+            "{}\n\n## {} session\n\n{}{}{}",
+            existing.trim_end(),
+            chrono::Local::now().format("%Y-%m-%d"),
+            marker,
+            summary,
+            PROMPTS
+        ),
+    };
+
+    std::fs::write(&path, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::hook::after_file_edit;
+
+    fn input(conversation: &str, file_path: Option<&str>) -> HookInput {
+        HookInput {
+            conversation_id: Some(conversation.to_string()),
+            file_path: file_path.map(str::to_string),
+            status: Some("completed".to_string()),
+            ..HookInput::default()
+        }
+    }
+
+    #[test]
+    fn counts_edits_across_a_log_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join(".ai")).unwrap();
+
+        // Earlier edits of the session sit in a log about to be rotated
+        let mut log = "[2026-01-01 00:00:00] [other] Edited: old.rs (1 change(s))\n".repeat(5000);
+        for file in ["a.rs", "b.rs", "c.rs"] {
+            log.push_str(&format!(
+                "[2026-01-01 00:00:00] [conv] Edited: {} (1 change(s))\n",
+                file
+            ));
+        }
+        std::fs::write(dir.path().join(EDIT_LOG), log).unwrap();
+
+        for file in ["d.rs", "e.rs", "a.rs"] {
+            after_file_edit::run(&input("conv", Some(file)), dir.path(), &[], &[]).unwrap();
+        }
+        assert!(dir.path().join(EDIT_LOG_ROTATED).is_file());
+
+        let output = run(&input("conv", None), dir.path()).unwrap();
+        let message = output["followup_message"].as_str().unwrap();
+        assert!(message.starts_with("This session made 6 edits to 5 files"));
+
+        let draft = std::fs::read_to_string(dir.path().join(LEARNINGS_PENDING)).unwrap();
+        for file in ["a.rs", "b.rs", "c.rs", "d.rs", "e.rs"] {
+            assert!(draft.contains(&format!("- `{}`", file)), "{} missing", file);
+        }
+        assert!(!draft.contains("old.rs"));
+    }
+}
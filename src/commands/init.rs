@@ -1,7 +1,16 @@
-use crate::embed::{self, FileCategory};
-use crate::fs::{copy_with_prompt, update_gitignore};
+use crate::config::{Config, ConfigError, ConflictPolicy, GitignoreMode, CONFIG_FILE};
+use crate::embed::source::{Source, SourceError};
+use crate::embed::template::Vars;
+use crate::embed::Category;
+use crate::fs::{copy_with_prompt, update_gitignore, write_managed_block, CopyResult};
 use crate::git::find_repo_root;
+use crate::stack;
+use crate::targets::Target;
+use crate::targets::{self, GenerateOptions, HookShell, Overrides, WriteMode};
 use console::style;
+use dialoguer::Confirm;
+use std::collections::BTreeMap;
+use std::path::Path;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -10,19 +19,86 @@ pub enum InitError {
         "Not in a git repository. Run from a directory with .git or its immediate subdirectory."
     )]
     NotInGitRepo,
-    #[error("Invalid category: {0}. Valid: ai, rules, commands, hooks, skills, agents")]
+    #[error("Invalid category: {0}. Valid: ai, rules, commands, hooks, skills, agents, lang, rust, typescript, python, go")]
     InvalidCategory(String),
+    #[error("Invalid target: {0}. Valid: {valid}", valid = targets::valid_ids())]
+    InvalidTarget(String),
+    #[error("Invalid hook shell: {0}. Valid: native, powershell, sh")]
+    InvalidHookShell(String),
+    #[error("Invalid variable: {0}. Expected key=value")]
+    InvalidVar(String),
+    #[error("Invalid gitignore mode: {0}. Valid: update, create, skip")]
+    InvalidGitignoreMode(String),
+    #[error("Invalid conflict policy: {0}. Valid: prompt, overwrite, skip")]
+    InvalidConflictPolicy(String),
+    #[error("No {CONFIG_FILE} found. Run `ai-init init` first")]
+    MissingConfig,
+    #[error(transparent)]
+    Source(#[from] SourceError),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     // #[error("User cancelled")]
     // Cancelled,
 }
 
-pub fn run(
-    only: Option<Vec<String>>,
-    skip: Option<Vec<String>>,
-    force: bool,
-) -> Result<(), InitError> {
+/// Command-line settings for init
+pub struct InitOptions {
+    pub only: Option<Vec<String>>,
+    pub skip: Option<Vec<String>>,
+    pub target: Option<Vec<String>>,
+    pub agents_words: Option<usize>,
+    pub hook_shell: Option<String>,
+    /// `key=value` template variables
+    pub vars: Vec<String>,
+    /// Template source; embedded when None
+    pub source: Option<String>,
+    /// .gitignore mode: update, create or skip
+    pub gitignore: Option<String>,
+    /// Conflict policy: prompt, overwrite or skip
+    pub conflict: Option<String>,
+    pub force: bool,
+}
+
+pub fn run(options: InitOptions) -> Result<(), InitError> {
+    install(options, false)
+}
+
+/// Refresh installed files using the settings recorded in `.ai-init.toml`
+pub fn update(force: bool) -> Result<(), InitError> {
+    install(
+        InitOptions {
+            only: None,
+            skip: None,
+            target: None,
+            agents_words: None,
+            hook_shell: None,
+            vars: Vec::new(),
+            source: None,
+            gitignore: None,
+            conflict: None,
+            force,
+        },
+        true,
+    )
+}
+
+/// Install files for the effective settings: flags first, then `.ai-init.toml`.
+/// Every run records the effective settings; update keeps project content files.
+fn install(options: InitOptions, updating: bool) -> Result<(), InitError> {
+    let InitOptions {
+        only,
+        skip,
+        target,
+        agents_words,
+        hook_shell,
+        vars,
+        source,
+        gitignore,
+        conflict,
+        force,
+    } = options;
     // 1. Find git repo root
     let repo_root = find_repo_root()?.ok_or(InitError::NotInGitRepo)?;
 
@@ -32,51 +108,182 @@ pub fn run(
         repo_root.display()
     );
 
-    // 2. Parse category filters
-    let only_cats = parse_categories(only)?;
-    let skip_cats = parse_categories(skip)?;
+    // 2. Merge flags with the recorded settings
+    let recorded = Config::load(&repo_root)?;
+    if updating && recorded.is_none() {
+        return Err(InitError::MissingConfig);
+    }
+    if let Some(config) = &recorded {
+        println!(
+            "{} Using settings from {} (gitignore: {}, conflict: {})",
+            style("→").blue(),
+            CONFIG_FILE,
+            config.gitignore.as_str(),
+            config.conflict.as_str()
+        );
+    }
+    let config = recorded.clone().unwrap_or_default();
+    let only = only.or(config.only);
+    let skip = skip.or(config.skip);
+    let source = source.or(config.source);
+    let agents_words = agents_words.or(config.agents_words);
+    let hook_shell = hook_shell.or(config.hook_shell);
+    let vars = parse_vars(config.vars, vars)?;
+    let gitignore = match gitignore {
+        Some(s) => GitignoreMode::from_str(&s).ok_or(InitError::InvalidGitignoreMode(s))?,
+        None => config.gitignore,
+    };
+    // --force overwrites for this run only
+    let recorded_conflict = match conflict {
+        Some(s) => ConflictPolicy::from_str(&s).ok_or(InitError::InvalidConflictPolicy(s))?,
+        None => config.conflict,
+    };
+    let conflict = if force {
+        ConflictPolicy::Overwrite
+    } else {
+        recorded_conflict
+    };
+
+    // 3. Parse category filters
+    let only_cats = parse_categories(only.clone())?;
+    let skip_cats = parse_categories(skip.clone())?;
+
+    // 4. Resolve targets from --target, the recorded targets or the tools the repo already uses
+    let targets = match target.or(config.targets) {
+        Some(ids) => {
+            let selected = targets::parse(&ids).map_err(InitError::InvalidTarget)?;
+            report_unselected(&repo_root, &selected);
+            selected
+        }
+        None => select_detected(&repo_root)?,
+    };
+
+    // 5. Get filtered artifacts and render them for each target
+    let shell = match &hook_shell {
+        Some(s) => HookShell::from_str(s).ok_or_else(|| InitError::InvalidHookShell(s.clone()))?,
+        None => HookShell::detect(&repo_root),
+    };
+    let stack = stack::detect(&repo_root);
+    if !stack.is_empty() {
+        println!("{} Detected stack: {}", style("→").blue(), stack.describe());
+    }
+    let template_source = Source::parse(source.as_deref());
+    if !template_source.is_embedded() {
+        println!(
+            "{} Templates from: {}",
+            style("→").blue(),
+            template_source.describe()
+        );
+    }
+    let artifacts =
+        template_source
+            .load()?
+            .select(only_cats.as_deref(), skip_cats.as_deref(), &stack);
+    let mut template_vars = Vars::detect(&repo_root, &stack);
+    for (key, value) in &vars {
+        template_vars.set(key, value);
+    }
+    let options = GenerateOptions {
+        repo_root: &repo_root,
+        agents_word_budget: agents_words.unwrap_or(targets::agents::DEFAULT_WORD_BUDGET),
+        hook_shell: shell,
+        vars: template_vars,
+        stack,
+        overrides: Overrides::load(),
+        only: only_cats,
+        skip: skip_cats,
+    };
+    if !options.overrides.is_empty() {
+        if let Some(dir) = Overrides::dir() {
+            println!("{} Overrides from: {}", style("→").blue(), dir.display());
+        }
+    }
+    let files = targets::generate(&targets, &artifacts, &options);
 
-    // 3. Get filtered file list
-    let files = embed::get_files_by_categories(only_cats.as_deref(), skip_cats.as_deref());
+    for target in &targets {
+        for moved in target.migrate(&repo_root)? {
+            println!("  {} {}", style("✓").green(), moved);
+        }
+    }
 
     println!("{} Installing {} files...", style("→").blue(), files.len());
 
-    // 4. Copy each file
+    // 6. Copy each file
     let mut installed = 0;
     let mut skipped = 0;
 
     for file in &files {
-        let target = repo_root.join(file.target_path);
+        let target = repo_root.join(&file.target_path);
+
+        // The skip policy keeps existing files, managed ones included, and
+        // update never replaces what the team has written into the context files
+        let keep = target.exists()
+            && (conflict == ConflictPolicy::Skip
+                || (updating && targets::PROJECT_FILES.contains(&file.target_path.as_str())));
+
+        let result = match file.mode {
+            _ if keep || file.is_current(&repo_root) => CopyResult::Skipped,
+            WriteMode::Replace => copy_with_prompt(
+                &target,
+                &file.content,
+                conflict == ConflictPolicy::Overwrite,
+            )?,
+            WriteMode::ManagedBlock { header } => {
+                write_managed_block(&target, header, &file.content)?
+            }
+        };
 
-        match copy_with_prompt(&target, file.content, force)? {
-            crate::fs::CopyResult::Created | crate::fs::CopyResult::Overwritten => {
+        match result {
+            CopyResult::Created | CopyResult::Overwritten => {
                 println!("  {} {}", style("✓").green(), file.target_path);
                 installed += 1;
             }
-            crate::fs::CopyResult::Skipped => {
+            CopyResult::Skipped => {
                 println!("  {} {} (skipped)", style("-").yellow(), file.target_path);
                 skipped += 1;
             }
         }
     }
 
-    // 5. Update .gitignore
+    // 7. Update .gitignore
     let gitignore_path = repo_root.join(".gitignore");
-    if gitignore_path.exists() {
-        let updated = update_gitignore(&gitignore_path)?;
-        if updated {
+    if gitignore == GitignoreMode::Create && !gitignore_path.exists() {
+        std::fs::write(&gitignore_path, "")?;
+    }
+    if gitignore != GitignoreMode::Skip && gitignore_path.exists() {
+        let added = update_gitignore(&gitignore_path, &targets::gitignore_entries(&targets))?;
+        if !added.is_empty() {
             println!(
-                "  {} .gitignore (added .cursor/ and .ai/)",
-                style("✓").green()
+                "  {} .gitignore (added {})",
+                style("✓").green(),
+                added.join(" and ")
             );
         }
     }
 
-    // 6. Summary
+    // 8. Record the effective choices so later runs and update reuse them
+    let settings = Config {
+        targets: Some(targets.iter().map(|t| t.id().to_string()).collect()),
+        only,
+        skip,
+        source,
+        agents_words,
+        hook_shell,
+        gitignore,
+        conflict: recorded_conflict,
+        vars,
+    };
+    if recorded.as_ref() != Some(&settings) {
+        settings.save(&repo_root)?;
+        println!("  {} {}", style("✓").green(), CONFIG_FILE);
+    }
+
+    // 9. Summary
     println!();
     println!(
-        "{} Installation complete: {} installed, {} skipped",
+        "{} {} complete: {} installed, {} skipped",
         style("✓").green().bold(),
+        if updating { "Update" } else { "Installation" },
         installed,
         skipped
     );
@@ -84,17 +291,79 @@ pub fn run(
     Ok(())
 }
 
-fn parse_categories(cats: Option<Vec<String>>) -> Result<Option<Vec<FileCategory>>, InitError> {
+fn parse_categories(cats: Option<Vec<String>>) -> Result<Option<Vec<Category>>, InitError> {
     match cats {
         None => Ok(None),
         Some(strs) => {
             let mut result = Vec::new();
             for s in strs {
-                let cat = FileCategory::from_str(&s)
-                    .ok_or_else(|| InitError::InvalidCategory(s.clone()))?;
+                let cat =
+                    Category::from_str(&s).ok_or_else(|| InitError::InvalidCategory(s.clone()))?;
                 result.push(cat);
             }
             Ok(Some(result))
         }
     }
 }
+
+/// Recorded template variables overridden by --var key=value
+fn parse_vars(
+    recorded: BTreeMap<String, String>,
+    overrides: Vec<String>,
+) -> Result<BTreeMap<String, String>, InitError> {
+    let mut vars = recorded;
+    for var in overrides {
+        match var.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                vars.insert(key.trim().to_string(), value.to_string());
+            }
+            _ => return Err(InitError::InvalidVar(var)),
+        }
+    }
+    Ok(vars)
+}
+
+/// Report detected tools and offer to install for them.
+/// Falls back to the default target when nothing is detected or the offer is declined.
+fn select_detected(repo_root: &Path) -> Result<Vec<Box<dyn Target>>, InitError> {
+    let detected = targets::detect(repo_root);
+    let fallback = || targets::parse(&[targets::DEFAULT_TARGET.to_string()]);
+
+    if detected.is_empty() {
+        return fallback().map_err(InitError::InvalidTarget);
+    }
+
+    println!("{} Detected AI tools:", style("→").blue());
+    for detection in &detected {
+        println!("  - {}", detection.describe());
+    }
+
+    let install_detected = Confirm::new()
+        .with_prompt("Install workflow files for these tools?")
+        .default(true)
+        .interact()
+        .unwrap_or(true);
+
+    if install_detected {
+        Ok(detected.into_iter().map(|d| d.target).collect())
+    } else {
+        fallback().map_err(InitError::InvalidTarget)
+    }
+}
+
+/// Mention detected tools that --target left out
+fn report_unselected(repo_root: &Path, selected: &[Box<dyn Target>]) {
+    let unselected: Vec<String> = targets::detect(repo_root)
+        .into_iter()
+        .filter(|d| !selected.iter().any(|t| t.id() == d.target.id()))
+        .map(|d| d.describe())
+        .collect();
+
+    if !unselected.is_empty() {
+        println!(
+            "{} Also detected: {} (add them to --target to install)",
+            style("!").yellow(),
+            unselected.join(", ")
+        );
+    }
+}
@@ -0,0 +1,407 @@
+use super::hook::LEARNINGS_PENDING;
+use crate::git::find_repo_root;
+use console::style;
+use dialoguer::Select;
+use std::path::Path;
+use thiserror::Error;
+
+const CONTEXT_PATH: &str = ".ai/ai-context.md";
+
+#[derive(Error, Debug)]
+pub enum LearnError {
+    #[error("Not in a git repository")]
+    NotInGitRepo,
+    #[error("No .ai/ai-context.md found. Run `ai-init init` or `ai-init context generate` first")]
+    MissingContext,
+    #[error("Invalid section: {0}. Valid: patterns, gotchas, decisions")]
+    InvalidSection(String),
+    #[error("Nothing to add: the learning is empty")]
+    Empty,
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Where a learning goes in ai-context.md
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    Patterns,
+    Gotchas,
+    Decisions,
+}
+
+impl Section {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Patterns => "patterns",
+            Self::Gotchas => "gotchas",
+            Self::Decisions => "decisions",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "patterns" | "pattern" => Some(Self::Patterns),
+            "gotchas" | "gotcha" | "pitfalls" => Some(Self::Gotchas),
+            "decisions" | "decision" => Some(Self::Decisions),
+            _ => None,
+        }
+    }
+
+    /// Lines of the template entries are appended under, most specific first
+    fn anchors(&self) -> &'static [&'static str] {
+        match self {
+            Self::Patterns => &["### Patterns We Follow"],
+            Self::Gotchas => &["**What has broken before:**", "## Common Pitfalls"],
+            Self::Decisions => &["**Major architectural decisions:**"],
+        }
+    }
+
+    /// Heading created when the context has none of the anchors
+    fn fallback_heading(&self) -> &'static str {
+        match self {
+            Self::Patterns => "## Patterns",
+            Self::Gotchas => "## Common Pitfalls",
+            Self::Decisions => "## Decisions",
+        }
+    }
+
+    /// Section for a prompt label in a pending learnings draft
+    fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "Pattern" => Some(Self::Patterns),
+            "Gotcha" => Some(Self::Gotchas),
+            "Decision" => Some(Self::Decisions),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of adding one learning
+enum Added {
+    Added,
+    Duplicate,
+}
+
+/// Append a dated learning to its section of ai-context.md
+pub fn add(text: String, section: String) -> Result<(), LearnError> {
+    let repo_root = find_repo_root()?.ok_or(LearnError::NotInGitRepo)?;
+    let section = Section::from_str(&section).ok_or(LearnError::InvalidSection(section))?;
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return Err(LearnError::Empty);
+    }
+
+    let mut context = read_context(&repo_root)?;
+    match add_learning(&mut context, section, &text) {
+        Added::Added => {
+            std::fs::write(repo_root.join(CONTEXT_PATH), &context)?;
+            println!(
+                "{} Added to {} ({})",
+                style("✓").green(),
+                CONTEXT_PATH,
+                section.as_str()
+            );
+        }
+        Added::Duplicate => println!(
+            "{} Already in {}: {}",
+            style("-").yellow(),
+            CONTEXT_PATH,
+            text
+        ),
+    }
+    Ok(())
+}
+
+/// Walk through the learnings filled into the stop hook's drafts, accepting or rejecting each
+pub fn review() -> Result<(), LearnError> {
+    let repo_root = find_repo_root()?.ok_or(LearnError::NotInGitRepo)?;
+    let pending_path = repo_root.join(LEARNINGS_PENDING);
+    let Ok(pending) = std::fs::read_to_string(&pending_path) else {
+        println!("{} No pending learnings", style("✓").green());
+        return Ok(());
+    };
+    let mut context = read_context(&repo_root)?;
+
+    let (header, mut entries) = split_entries(&pending);
+    let (mut accepted, mut rejected, mut skipped) = (0, 0, 0);
+    let mut stopped = false;
+
+    for entry in entries.iter_mut() {
+        for candidate in entry.candidates.iter_mut() {
+            if stopped {
+                skipped += 1;
+                continue;
+            }
+            println!();
+            println!("{}", style(&entry.title).bold());
+            println!(
+                "{} {} ({})",
+                style("→").blue(),
+                candidate.text,
+                candidate.section.as_str()
+            );
+
+            let choice = Select::new()
+                .with_prompt("Add to ai-context.md?")
+                .items(["Accept", "Reject", "Skip for now"])
+                .default(0)
+                .interact()
+                .ok();
+            match choice {
+                Some(0) => {
+                    match add_learning(&mut context, candidate.section, &candidate.text) {
+                        Added::Added => println!("{} Added", style("✓").green()),
+                        Added::Duplicate => {
+                            println!("{} Already in ai-context.md", style("-").yellow())
+                        }
+                    }
+                    candidate.decided = true;
+                    accepted += 1;
+                }
+                Some(1) => {
+                    candidate.decided = true;
+                    rejected += 1;
+                }
+                Some(_) => skipped += 1,
+                // No terminal or cancelled: leave the rest for later
+                None => {
+                    stopped = true;
+                    skipped += 1;
+                }
+            }
+        }
+    }
+
+    if accepted + rejected + skipped == 0 {
+        let drafts = entries.len();
+        println!(
+            "{} No learnings filled in yet{}. Fill in the Pattern, Gotcha and Decision lines in {}",
+            style("-").yellow(),
+            if drafts > 0 {
+                format!(" ({} drafts)", drafts)
+            } else {
+                String::new()
+            },
+            LEARNINGS_PENDING
+        );
+        return Ok(());
+    }
+
+    if accepted + rejected == 0 {
+        println!();
+        println!(
+            "{} Nothing decided; {} learnings left in {}",
+            style("-").yellow(),
+            skipped,
+            LEARNINGS_PENDING
+        );
+        return Ok(());
+    }
+
+    if accepted > 0 {
+        std::fs::write(repo_root.join(CONTEXT_PATH), &context)?;
+    }
+    let remaining: Vec<String> = entries.iter().filter_map(Entry::remaining).collect();
+    if remaining.is_empty() {
+        std::fs::remove_file(&pending_path)?;
+    } else {
+        std::fs::write(
+            &pending_path,
+            format!("{}\n\n{}\n", header.trim_end(), remaining.join("\n\n")),
+        )?;
+    }
+
+    println!();
+    println!(
+        "{} Accepted {}, rejected {}, skipped {}",
+        style("✓").green(),
+        accepted,
+        rejected,
+        skipped
+    );
+    Ok(())
+}
+
+fn read_context(repo_root: &Path) -> Result<String, LearnError> {
+    std::fs::read_to_string(repo_root.join(CONTEXT_PATH)).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => LearnError::MissingContext,
+        _ => LearnError::Io(e),
+    })
+}
+
+/// Append `- **<date>**: <text>` to the end of the section's block,
+/// unless the context already has an entry with the same wording
+fn add_learning(context: &mut String, section: Section, text: &str) -> Added {
+    let key = normalize(text);
+    if context
+        .lines()
+        .any(|line| list_item(line).is_some_and(|item| normalize(item) == key))
+    {
+        return Added::Duplicate;
+    }
+
+    let date = chrono::Local::now().format("%Y-%m-%d");
+    let lines: Vec<&str> = context.lines().collect();
+    let anchor = section
+        .anchors()
+        .iter()
+        .find_map(|anchor| lines.iter().position(|l| l.trim_end().starts_with(anchor)));
+
+    let Some(anchor) = anchor else {
+        // No matching heading: add one before the usage notes, else at the end
+        let block = format!(
+            "{}\n\n- **{}**: {}\n\n",
+            section.fallback_heading(),
+            date,
+            text
+        );
+        match context.find("\n## Usage Notes") {
+            Some(i) => context.insert_str(i + 1, &block),
+            None => {
+                let trimmed = context.trim_end().len();
+                context.truncate(trimmed);
+                context.push_str("\n\n");
+                context.push_str(block.trim_end());
+                context.push('\n');
+            }
+        }
+        return Added::Added;
+    };
+
+    // The block ends at the next heading, rule or bold label
+    let end = lines[anchor + 1..]
+        .iter()
+        .position(|l| {
+            let l = l.trim();
+            l.starts_with('#') || l == "---" || (l.starts_with("**") && l.ends_with(":**"))
+        })
+        .map_or(lines.len(), |i| anchor + 1 + i);
+    let last = (anchor..end)
+        .rev()
+        .find(|i| !lines[*i].trim().is_empty())
+        .unwrap_or(anchor);
+
+    // Continue a numbered list rather than switching to bullets
+    let number = lines[anchor + 1..=last.max(anchor)]
+        .iter()
+        .rev()
+        .find_map(|l| {
+            l.split_once(". ")
+                .and_then(|(n, _)| n.parse::<usize>().ok())
+        });
+    let item = match number {
+        Some(n) => format!("{}. **{}**: {}", n + 1, date, text),
+        None => format!("- **{}**: {}", date, text),
+    };
+
+    let mut result: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    if last == anchor {
+        result.insert(last + 1, String::new());
+        result.insert(last + 2, item);
+    } else {
+        result.insert(last + 1, item);
+    }
+    *context = result.join("\n") + "\n";
+    Added::Added
+}
+
+/// Text of a bullet or numbered item, without a leading `**date**:` or `**label**:`
+fn list_item(line: &str) -> Option<&str> {
+    let line = line.trim();
+    let item = line.strip_prefix("- ").or_else(|| {
+        let (n, rest) = line.split_once(". ")?;
+        n.parse::<usize>().ok().map(|_| rest)
+    })?;
+    Some(
+        item.strip_prefix("**")
+            .and_then(|rest| rest.split_once("**: ").map(|(_, text)| text))
+            .unwrap_or(item),
+    )
+}
+
+/// Lowercase words without punctuation, for duplicate detection
+fn normalize(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A filled-in `- **Pattern**: ...` line of a draft
+struct Candidate {
+    line: usize,
+    section: Section,
+    text: String,
+    decided: bool,
+}
+
+/// One `## <date> session` draft
+struct Entry {
+    title: String,
+    lines: Vec<String>,
+    candidates: Vec<Candidate>,
+}
+
+impl Entry {
+    /// What stays in the pending file: None once every learning is decided
+    fn remaining(&self) -> Option<String> {
+        let decided: Vec<usize> = self
+            .candidates
+            .iter()
+            .filter(|c| c.decided)
+            .map(|c| c.line)
+            .collect();
+        if !self.candidates.is_empty() && decided.len() == self.candidates.len() {
+            return None;
+        }
+        let lines: Vec<&str> = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !decided.contains(i))
+            .map(|(_, l)| l.as_str())
+            .collect();
+        Some(lines.join("\n").trim_end().to_string())
+    }
+}
+
+/// Header and session entries of the pending learnings file
+fn split_entries(pending: &str) -> (String, Vec<Entry>) {
+    let mut header = String::new();
+    let mut entries: Vec<Entry> = Vec::new();
+
+    for line in pending.lines() {
+        if line.starts_with("## ") {
+            entries.push(Entry {
+                title: line.trim_start_matches("## ").to_string(),
+                lines: Vec::new(),
+                candidates: Vec::new(),
+            });
+        }
+        let Some(entry) = entries.last_mut() else {
+            header.push_str(line);
+            header.push('\n');
+            continue;
+        };
+
+        // Prompts the stop hook writes: `- **Pattern**: `, `- **Gotcha**: `, `- **Decision**: `
+        let filled = line
+            .trim()
+            .strip_prefix("- **")
+            .and_then(|rest| rest.split_once("**:"))
+            .and_then(|(label, text)| Some((Section::from_label(label)?, text.trim())))
+            .filter(|(_, text)| !text.is_empty());
+        if let Some((section, text)) = filled {
+            entry.candidates.push(Candidate {
+                line: entry.lines.len(),
+                section,
+                text: text.to_string(),
+                decided: false,
+            });
+        }
+        entry.lines.push(line.to_string());
+    }
+
+    (header, entries)
+}
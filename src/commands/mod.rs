@@ -1,4 +1,12 @@
+pub mod adr;
+pub mod archive;
+pub mod context;
+pub mod diff;
 pub mod doctor;
+pub mod hook;
 pub mod init;
+pub mod learn;
+pub mod new;
 pub mod uninstall;
 pub mod upgrade;
+pub mod workflow;
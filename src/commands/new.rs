@@ -0,0 +1,204 @@
+use super::adr::{self, AdrError};
+use crate::embed::{self, frontmatter};
+use crate::fs::{copy_with_prompt, CopyResult};
+use crate::git::find_repo_root;
+use console::style;
+use std::path::Path;
+use thiserror::Error;
+
+/// Statuses of an active document that is no longer in progress
+const FINISHED: &[&str] = &["complete", "done", "aborted", "archived"];
+
+#[derive(Error, Debug)]
+pub enum NewError {
+    #[error("Not in a git repository")]
+    NotInGitRepo,
+    #[error("Invalid document kind: {0}. Valid: research, plan, decision")]
+    InvalidKind(String),
+    #[error("Invalid slug: {0:?}. Use a short kebab-case name such as `cache-invalidation`")]
+    InvalidSlug(String),
+    #[error(
+        "{path} is in progress (task: {task}, status: {status}). Finish it first or pass --force to replace it"
+    )]
+    InProgress {
+        path: String,
+        task: String,
+        status: String,
+    },
+    #[error(transparent)]
+    Adr(#[from] AdrError),
+    #[error("No template for {0}")]
+    MissingTemplate(String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Kind of workflow document, each backed by a template in `.ai/template/`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocKind {
+    Research,
+    Plan,
+    Decision,
+}
+
+impl DocKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Research => "research",
+            Self::Plan => "plan",
+            Self::Decision => "decision",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "research" => Some(Self::Research),
+            "plan" | "planning" => Some(Self::Plan),
+            "decision" | "adr" => Some(Self::Decision),
+            _ => None,
+        }
+    }
+
+    /// Template name relative to `.ai/`
+    fn template(&self) -> String {
+        format!("template/{}.md", self.as_str())
+    }
+
+    /// Where the research or plan in progress lives, relative to repo root
+    pub fn active_path(&self) -> String {
+        format!(".ai/active-{}.md", self.as_str())
+    }
+
+    /// Status a new document starts in
+    pub fn initial_status(&self) -> &'static str {
+        match self {
+            Self::Research => "research",
+            Self::Plan => "planning",
+            Self::Decision => "proposed",
+        }
+    }
+}
+
+/// Instantiate a document template for `slug` and write it where the workflow expects it
+pub fn run(kind: String, slug: String, force: bool) -> Result<(), NewError> {
+    let repo_root = find_repo_root()?.ok_or(NewError::NotInGitRepo)?;
+    let kind = DocKind::from_str(&kind).ok_or(NewError::InvalidKind(kind))?;
+    let slug = normalize_slug(&slug).ok_or(NewError::InvalidSlug(slug))?;
+    // Decisions are numbered rather than replaced
+    if kind == DocKind::Decision {
+        return Ok(adr::new(slug)?);
+    }
+
+    create(&repo_root, kind, &slug, force)?;
+    Ok(())
+}
+
+/// Write the active research or plan document for `slug`.
+/// Returns false if the user chose to keep an existing finished document.
+pub fn create(repo_root: &Path, kind: DocKind, slug: &str, force: bool) -> Result<bool, NewError> {
+    let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let template =
+        read_template(repo_root, kind).ok_or_else(|| NewError::MissingTemplate(kind.template()))?;
+    let path = kind.active_path();
+    if !force {
+        check_not_in_progress(repo_root, &path)?;
+    }
+    let mut content = active_doc(&template, kind, slug, &date);
+    // A plan links the research it builds on
+    if kind == DocKind::Plan && repo_root.join(DocKind::Research.active_path()).exists() {
+        content = frontmatter::set(&content, "research", &DocKind::Research.active_path());
+    }
+
+    match copy_with_prompt(&repo_root.join(&path), &content, force)? {
+        result @ (CopyResult::Created | CopyResult::Overwritten) => {
+            println!(
+                "{} {} {} (task: {}, status: {})",
+                style("✓").green(),
+                if matches!(result, CopyResult::Created) {
+                    "Created"
+                } else {
+                    "Replaced"
+                },
+                path,
+                slug,
+                kind.initial_status()
+            );
+            Ok(true)
+        }
+        CopyResult::Skipped => {
+            println!("{} Kept existing {}", style("-").yellow(), path);
+            Ok(false)
+        }
+    }
+}
+
+/// Lowercase kebab-case, or None if nothing usable is left
+pub fn normalize_slug(slug: &str) -> Option<String> {
+    let mut result = String::new();
+    for c in slug.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            result.push(c.to_ascii_lowercase());
+        } else if !result.is_empty() && !result.ends_with('-') {
+            result.push('-');
+        }
+    }
+    let result = result.trim_end_matches('-').to_string();
+    (!result.is_empty()).then_some(result)
+}
+
+/// `cache-invalidation` → `Cache Invalidation`
+pub fn title_from_slug(slug: &str) -> String {
+    slug.split('-')
+        .filter(|w| !w.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The installed template, so local edits apply, else the embedded one
+pub fn read_template(repo_root: &Path, kind: DocKind) -> Option<String> {
+    let name = kind.template();
+    if let Ok(content) = std::fs::read_to_string(repo_root.join(".ai").join(&name)) {
+        return Some(content);
+    }
+    embed::get_all_artifacts()
+        .into_iter()
+        .find(|a| a.name == name)
+        .map(|a| a.content.into_owned())
+}
+
+/// Refuse to replace an active document whose status says work is still going on
+fn check_not_in_progress(repo_root: &Path, path: &str) -> Result<(), NewError> {
+    let Ok(content) = std::fs::read_to_string(repo_root.join(path)) else {
+        return Ok(());
+    };
+    let fm = frontmatter::parse(&content).0.unwrap_or_default();
+    let field = |key: &str| {
+        fm.get_str(key)
+            .filter(|v| !v.starts_with('['))
+            .map(str::to_string)
+    };
+    let status = field("status").unwrap_or_else(|| "unknown".to_string());
+    if FINISHED.contains(&status.as_str()) {
+        return Ok(());
+    }
+    Err(NewError::InProgress {
+        path: path.to_string(),
+        task: field("task").unwrap_or_else(|| "unknown".to_string()),
+        status,
+    })
+}
+
+/// Research or plan template with its frontmatter and title filled in
+fn active_doc(template: &str, kind: DocKind, slug: &str, date: &str) -> String {
+    let content = frontmatter::set(template, "task", slug);
+    let content = frontmatter::set(&content, "created", date);
+    let content = frontmatter::set(&content, "status", kind.initial_status());
+    content.replacen("[TASK]", &title_from_slug(slug), 1)
+}
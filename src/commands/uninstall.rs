@@ -1,13 +1,23 @@
+use crate::config::{Config, ConfigError};
+use crate::embed::source::{Source, SourceError};
+use crate::fs::{remove_managed_block, remove_with_empty_parents};
 use crate::git::find_repo_root;
+use crate::targets::{self, overrides, GenerateOptions, GeneratedFile, Origin, Target, WriteMode};
 use console::style;
 use dialoguer::Confirm;
-use std::fs;
+use std::path::Path;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum UninstallError {
     #[error("Not in a git repository")]
     NotInGitRepo,
+    #[error("Invalid target: {0}. Valid: {valid}", valid = targets::valid_ids())]
+    InvalidTarget(String),
+    #[error(transparent)]
+    Source(#[from] SourceError),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     #[error("Dialog error: {0}")]
@@ -16,18 +26,89 @@ pub enum UninstallError {
     Cancelled,
 }
 
-pub fn run(force: bool) -> Result<(), UninstallError> {
+/// Stands in for `{{date}}` so installed files match whatever day they were written
+const DATE_MASK: &str = "\u{0}date\u{0}";
+
+/// Files of one target, or of the shared `.ai/` set, found at their install paths
+struct Removal {
+    label: &'static str,
+    /// Unchanged since install, or managed files whose ai-init section is stripped
+    owned: Vec<GeneratedFile>,
+    /// Edited or written by someone else; removed only on confirmation
+    changed: Vec<GeneratedFile>,
+}
+
+impl Removal {
+    fn new(label: &'static str, files: Vec<GeneratedFile>, repo_root: &Path) -> Option<Self> {
+        let (owned, changed): (Vec<_>, Vec<_>) = files
+            .into_iter()
+            .filter(|f| f.is_present(repo_root))
+            .partition(|f| is_owned(f, repo_root));
+        (!owned.is_empty() || !changed.is_empty()).then_some(Self {
+            label,
+            owned,
+            changed,
+        })
+    }
+}
+
+pub fn run(force: bool, target: Option<Vec<String>>) -> Result<(), UninstallError> {
     let repo_root = find_repo_root()?.ok_or(UninstallError::NotInGitRepo)?;
+    let config = Config::load_or_default(&repo_root)?;
+    let selected = targets::resolve(target.or(config.targets.clone()), &repo_root)
+        .map_err(UninstallError::InvalidTarget)?;
+
+    // Render what init installs from the recorded source and settings, with every pack
+    let artifacts = Source::parse(config.source.as_deref()).load()?.every();
+    let mut options = GenerateOptions {
+        only: None,
+        skip: None,
+        ..GenerateOptions::from_config(&repo_root, &config)?
+    };
+    // Files stamped with the install date still belong to ai-init on later days
+    options.vars.set("date", DATE_MASK);
+    // Effective files of the given targets, with their extra override files
+    let effective = |scope: &[Box<dyn Target>]| -> Vec<GeneratedFile> {
+        let artifacts: Vec<_> = artifacts.iter().collect();
+        let rendered = scope
+            .iter()
+            .flat_map(|t| t.render(&artifacts, &options))
+            .collect();
+        options
+            .overrides
+            .apply(rendered, scope, &options)
+            .into_iter()
+            // Shared extras are handled with .ai/
+            .filter(|(f, origin)| {
+                *origin != Origin::Extra
+                    || overrides::owner(&f.target_path)
+                        .is_some_and(|(id, _)| scope.iter().any(|t| t.id() == id))
+            })
+            .map(|(f, _)| f)
+            .collect()
+    };
 
-    let ai_dir = repo_root.join(".ai");
-    let cursor_dir = repo_root.join(".cursor");
+    // Installed files of each selected target
+    let mut removals: Vec<Removal> = selected
+        .iter()
+        .filter_map(|t| Removal::new(t.id(), effective(std::slice::from_ref(t)), &repo_root))
+        .collect();
 
-    let ai_exists = ai_dir.exists();
-    let cursor_exists = cursor_dir.exists();
+    // .ai/ and extra files outside every tool's directories are shared,
+    // so keep them while any other target is still installed
+    let others: Vec<Box<dyn Target>> = targets::all()
+        .into_iter()
+        .filter(|t| !selected.iter().any(|s| s.id() == t.id()))
+        .collect();
+    let others_installed = effective(&others).iter().any(|f| f.is_present(&repo_root));
+    if !others_installed {
+        let shared = targets::generate(&[], &artifacts, &options);
+        removals.extend(Removal::new("shared", shared, &repo_root));
+    }
 
-    if !ai_exists && !cursor_exists {
+    if removals.is_empty() {
         println!(
-            "{} Nothing to uninstall. No .ai/ or .cursor/ directories found.",
+            "{} Nothing to uninstall. No AI workflow files found.",
             style("✓").green()
         );
         return Ok(());
@@ -35,17 +116,24 @@ pub fn run(force: bool) -> Result<(), UninstallError> {
 
     // Show what will be removed
     println!("{} The following will be removed:", style("!").yellow());
-    if ai_exists {
-        println!("  - .ai/");
+    for removal in removals.iter().filter(|r| !r.owned.is_empty()) {
+        println!("  - {} ({} files)", removal.label, removal.owned.len());
     }
-    if cursor_exists {
-        println!("  - .cursor/");
+    let changed: Vec<&GeneratedFile> = removals.iter().flat_map(|r| &r.changed).collect();
+    if !changed.is_empty() {
+        println!(
+            "{} Changed since ai-init installed them, kept unless you confirm each:",
+            style("!").yellow()
+        );
+        for file in &changed {
+            println!("  - {}", file.target_path);
+        }
     }
 
     // Confirm
     if !force {
         let confirm = Confirm::new()
-            .with_prompt("Are you sure you want to remove these directories?")
+            .with_prompt("Are you sure you want to remove these files?")
             .default(false)
             .interact()?;
 
@@ -54,14 +142,40 @@ pub fn run(force: bool) -> Result<(), UninstallError> {
         }
     }
 
-    // Remove directories
-    if ai_exists {
-        fs::remove_dir_all(&ai_dir)?;
-        println!("  {} Removed .ai/", style("✓").green());
+    // Remove files
+    for removal in &removals {
+        for file in &removal.owned {
+            remove(file, &repo_root)?;
+        }
+        if !removal.owned.is_empty() {
+            println!("  {} Removed {} files", style("✓").green(), removal.label);
+        }
+
+        for file in &removal.changed {
+            // --force never removes what someone else wrote
+            let confirmed = !force
+                && Confirm::new()
+                    .with_prompt(format!(
+                        "{} was changed since ai-init installed it. Remove it anyway?",
+                        file.target_path
+                    ))
+                    .default(false)
+                    .interact()
+                    .unwrap_or(false);
+            if confirmed {
+                remove(file, &repo_root)?;
+                println!("  {} Removed {}", style("✓").green(), file.target_path);
+            } else {
+                println!("  {} Kept {}", style("-").yellow(), file.target_path);
+            }
+        }
     }
-    if cursor_exists {
-        fs::remove_dir_all(&cursor_dir)?;
-        println!("  {} Removed .cursor/", style("✓").green());
+
+    if repo_root.join(".ai").is_dir() && !others_installed {
+        println!(
+            "  {} Kept .ai/: it still holds files that were changed or added after install",
+            style("-").yellow()
+        );
     }
 
     println!();
@@ -69,3 +183,44 @@ pub fn run(force: bool) -> Result<(), UninstallError> {
 
     Ok(())
 }
+
+/// Whether the installed file is still what ai-init wrote: the generated content
+/// as of any install date,
+/// or a managed file that carries the ai-init section
+fn is_owned(file: &GeneratedFile, repo_root: &Path) -> bool {
+    match file.mode {
+        WriteMode::Replace => std::fs::read_to_string(repo_root.join(&file.target_path))
+            .is_ok_and(|installed| matches_with_dates(&installed, &file.content)),
+        WriteMode::ManagedBlock { .. } => file.is_present(repo_root),
+    }
+}
+
+/// Whether `installed` is `expected` with a `YYYY-MM-DD` date in place of each mask
+fn matches_with_dates(installed: &str, expected: &str) -> bool {
+    let mut rest = installed;
+    for (i, piece) in expected.split(DATE_MASK).enumerate() {
+        if i > 0 {
+            let Some((date, tail)) = rest.split_at_checked(10) else {
+                return false;
+            };
+            if chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+                return false;
+            }
+            rest = tail;
+        }
+        let Some(tail) = rest.strip_prefix(piece) else {
+            return false;
+        };
+        rest = tail;
+    }
+    rest.is_empty()
+}
+
+/// Delete a generated file, or strip only the ai-init section from a managed one
+fn remove(file: &GeneratedFile, repo_root: &Path) -> std::io::Result<()> {
+    let path = repo_root.join(&file.target_path);
+    match file.mode {
+        WriteMode::Replace => remove_with_empty_parents(&path, repo_root),
+        WriteMode::ManagedBlock { header } => remove_managed_block(&path, header).map(|_| ()),
+    }
+}
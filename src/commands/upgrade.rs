@@ -32,7 +32,7 @@ pub fn run(skip_confirm: bool) -> Result<(), UpgradeError> {
         get_latest_release(GITHUB_REPO).map_err(|e| UpgradeError::CheckFailed(e.to_string()))?;
 
     let current = Version::parse(CURRENT_VERSION).unwrap();
-    let latest_version = Version::parse(&latest.version.trim_start_matches('v'))
+    let latest_version = Version::parse(latest.version.trim_start_matches('v'))
         .map_err(|e| UpgradeError::CheckFailed(format!("Invalid version: {}", e)))?;
 
     // 2. Compare versions
@@ -0,0 +1,355 @@
+use super::new::{self, normalize_slug, read_template, DocKind, NewError};
+use crate::embed::frontmatter;
+use crate::git::find_repo_root;
+use console::style;
+use std::path::Path;
+use thiserror::Error;
+
+/// Plan sections that must be filled in before implementation starts
+pub const REQUIRED_PLAN_SECTIONS: &[&str] = &[
+    "Architectural Decisions",
+    "Implementation Steps",
+    "File Changes",
+    "Testing Strategy",
+];
+
+/// Document statuses, as read by the session-start hooks
+const RESEARCH: &str = "research";
+const COMPLETE: &str = "complete";
+const PLANNING: &str = "planning";
+const IMPLEMENTING: &str = "implementing";
+const DONE: &str = "done";
+const ABORTED: &str = "aborted";
+
+#[derive(Error, Debug)]
+pub enum WorkflowError {
+    #[error("Not in a git repository")]
+    NotInGitRepo,
+    #[error("Invalid slug: {0:?}. Use a short kebab-case name such as `cache-invalidation`")]
+    InvalidSlug(String),
+    #[error("Task `{task}` is in {phase}. Advance or abort it first, or pass --force")]
+    InProgress { task: String, phase: &'static str },
+    #[error("No task in progress. Start one with `ai-init workflow start <slug>`")]
+    NothingActive,
+    #[error("Task `{0}` is already done. Start a new one with `ai-init workflow start <slug>`")]
+    AlreadyDone(String),
+    #[error("{0} has no task in its frontmatter")]
+    MissingTask(String),
+    #[error("The plan isn't ready for implementation:\n  - {}", .0.join("\n  - "))]
+    PlanNotReady(Vec<String>),
+    #[error(transparent)]
+    New(#[from] NewError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Where the task in progress stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Idle,
+    Research,
+    Planning,
+    Implementing,
+    Done,
+}
+
+impl Phase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Research => "research",
+            Self::Planning => "planning",
+            Self::Implementing => "implementing",
+            Self::Done => "done",
+        }
+    }
+
+    /// What to do next, for status output
+    fn hint(&self) -> &'static str {
+        match self {
+            Self::Idle => "Start a task with `ai-init workflow start <slug>` or `/research`",
+            Self::Research => {
+                "Fill in .ai/active-research.md, then `ai-init workflow advance` to start planning"
+            }
+            Self::Planning => {
+                "Fill in .ai/active-plan.md, then `ai-init workflow advance` to start implementing"
+            }
+            Self::Implementing => {
+                "Follow the plan step by step, then `ai-init workflow advance` when it ships"
+            }
+            Self::Done => {
+                "Record what you learned in .ai/ai-context.md, then `ai-init archive` the documents"
+            }
+        }
+    }
+}
+
+/// An active research or plan document
+pub struct Doc {
+    pub path: String,
+    pub task: Option<String>,
+    pub status: String,
+    content: String,
+}
+
+impl Doc {
+    fn read(repo_root: &Path, kind: DocKind) -> std::io::Result<Option<Self>> {
+        let path = kind.active_path();
+        let content = match std::fs::read_to_string(repo_root.join(&path)) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let fm = frontmatter::parse(&content).0.unwrap_or_default();
+        let field = |key: &str| {
+            fm.get_str(key)
+                .filter(|v| !v.starts_with('['))
+                .map(str::to_string)
+        };
+        Ok(Some(Self {
+            path,
+            task: field("task"),
+            status: field("status").unwrap_or_else(|| kind.initial_status().to_string()),
+            content,
+        }))
+    }
+
+    fn is_finished(&self) -> bool {
+        [COMPLETE, DONE, ABORTED].contains(&self.status.as_str())
+    }
+
+    fn set_status(&mut self, repo_root: &Path, status: &str) -> std::io::Result<()> {
+        self.content = frontmatter::set(&self.content, "status", status);
+        self.status = status.to_string();
+        std::fs::write(repo_root.join(&self.path), &self.content)
+    }
+}
+
+/// The active documents of the repository
+pub struct State {
+    pub research: Option<Doc>,
+    pub plan: Option<Doc>,
+}
+
+impl State {
+    pub fn load(repo_root: &Path) -> std::io::Result<Self> {
+        Ok(Self {
+            research: Doc::read(repo_root, DocKind::Research)?,
+            plan: Doc::read(repo_root, DocKind::Plan)?,
+        })
+    }
+
+    pub fn phase(&self) -> Phase {
+        let research = self.research.as_ref().map(|d| d.status.as_str());
+        let plan = self.plan.as_ref().map(|d| d.status.as_str());
+        match (research, plan) {
+            (_, Some(PLANNING)) => Phase::Planning,
+            (_, Some(IMPLEMENTING)) => Phase::Implementing,
+            (Some(RESEARCH), _) => Phase::Research,
+            (_, Some(DONE)) => Phase::Done,
+            // Finished research without a plan yet
+            (Some(COMPLETE), None) => Phase::Research,
+            _ => Phase::Idle,
+        }
+    }
+
+    /// Task of the plan, else of the research
+    pub fn task(&self) -> Option<&str> {
+        [&self.plan, &self.research]
+            .into_iter()
+            .flatten()
+            .find_map(|d| d.task.as_deref())
+    }
+}
+
+/// Print the phase, the active documents and anything blocking the next step
+pub fn status() -> Result<(), WorkflowError> {
+    let repo_root = find_repo_root()?.ok_or(WorkflowError::NotInGitRepo)?;
+    let state = State::load(&repo_root)?;
+    let phase = state.phase();
+
+    match state.task() {
+        Some(task) if phase != Phase::Idle => println!(
+            "{} Task {}: {}",
+            style("→").blue(),
+            style(task).bold(),
+            phase.as_str()
+        ),
+        _ => println!("{} No task in progress", style("-").yellow()),
+    }
+    for doc in [&state.research, &state.plan].into_iter().flatten() {
+        println!("  {:<24} {}", doc.path, doc.status);
+    }
+
+    if phase == Phase::Planning {
+        let problems = check_plan(&repo_root, &state);
+        if problems.is_empty() {
+            println!("{} Plan is ready for implementation", style("✓").green());
+        }
+        for problem in &problems {
+            println!("{} {}", style("!").yellow(), problem);
+        }
+    }
+
+    println!();
+    println!("{} {}", style("→").blue(), phase.hint());
+    Ok(())
+}
+
+/// Begin a task with a fresh research document
+pub fn start(slug: String, force: bool) -> Result<(), WorkflowError> {
+    let repo_root = find_repo_root()?.ok_or(WorkflowError::NotInGitRepo)?;
+    let slug = normalize_slug(&slug).ok_or(WorkflowError::InvalidSlug(slug))?;
+    let state = State::load(&repo_root)?;
+
+    let phase = state.phase();
+    if !force && !matches!(phase, Phase::Idle | Phase::Done) {
+        return Err(WorkflowError::InProgress {
+            task: state.task().unwrap_or("unknown").to_string(),
+            phase: phase.as_str(),
+        });
+    }
+
+    if new::create(&repo_root, DocKind::Research, &slug, force)? {
+        println!("{} {}", style("→").blue(), Phase::Research.hint());
+    }
+    Ok(())
+}
+
+/// Move the task to its next phase: research → planning → implementing → done
+pub fn advance() -> Result<(), WorkflowError> {
+    let repo_root = find_repo_root()?.ok_or(WorkflowError::NotInGitRepo)?;
+    let mut state = State::load(&repo_root)?;
+
+    match state.phase() {
+        Phase::Idle => return Err(WorkflowError::NothingActive),
+        Phase::Done => {
+            let task = state.task().unwrap_or("unknown").to_string();
+            return Err(WorkflowError::AlreadyDone(task));
+        }
+        Phase::Research => {
+            let Some(research) = state.research.as_mut() else {
+                return Err(WorkflowError::NothingActive);
+            };
+            let task = research
+                .task
+                .clone()
+                .ok_or_else(|| WorkflowError::MissingTask(research.path.clone()))?;
+            if !new::create(&repo_root, DocKind::Plan, &task, false)? {
+                return Ok(());
+            }
+            research.set_status(&repo_root, COMPLETE)?;
+            println!("{} {}", style("→").blue(), Phase::Planning.hint());
+        }
+        Phase::Planning => {
+            let problems = check_plan(&repo_root, &state);
+            if !problems.is_empty() {
+                return Err(WorkflowError::PlanNotReady(problems));
+            }
+            if let Some(plan) = state.plan.as_mut() {
+                plan.set_status(&repo_root, IMPLEMENTING)?;
+                println!(
+                    "{} {} is now {}",
+                    style("✓").green(),
+                    plan.path,
+                    IMPLEMENTING
+                );
+            }
+            println!("{} {}", style("→").blue(), Phase::Implementing.hint());
+        }
+        Phase::Implementing => {
+            if let Some(plan) = state.plan.as_mut() {
+                plan.set_status(&repo_root, DONE)?;
+                println!("{} {} is now {}", style("✓").green(), plan.path, DONE);
+            }
+            if let Some(research) = state.research.as_mut().filter(|d| !d.is_finished()) {
+                research.set_status(&repo_root, COMPLETE)?;
+            }
+            println!("{} {}", style("→").blue(), Phase::Done.hint());
+        }
+    }
+    Ok(())
+}
+
+/// Mark the documents still in progress as aborted
+pub fn abort() -> Result<(), WorkflowError> {
+    let repo_root = find_repo_root()?.ok_or(WorkflowError::NotInGitRepo)?;
+    let mut state = State::load(&repo_root)?;
+    if matches!(state.phase(), Phase::Idle | Phase::Done) {
+        return Err(WorkflowError::NothingActive);
+    }
+
+    let task = state.task().unwrap_or("unknown").to_string();
+    for doc in [&mut state.research, &mut state.plan].into_iter().flatten() {
+        if !doc.is_finished() {
+            doc.set_status(&repo_root, ABORTED)?;
+        }
+    }
+    println!("{} Aborted task {}", style("✓").green(), task);
+    Ok(())
+}
+
+/// Reasons the plan can't move to implementation
+pub fn check_plan(repo_root: &Path, state: &State) -> Vec<String> {
+    let Some(plan) = &state.plan else {
+        return vec!["No .ai/active-plan.md".to_string()];
+    };
+    let mut problems = Vec::new();
+
+    let (fm, body) = frontmatter::parse(&plan.content);
+    let reference = fm
+        .unwrap_or_default()
+        .get_str("research")
+        .map(str::to_string);
+    let research_path = DocKind::Research.active_path();
+    match reference {
+        Some(path) if !repo_root.join(&path).exists() => {
+            problems.push(format!("Plan references {}, which doesn't exist", path));
+        }
+        Some(path) if path == research_path => {
+            let research_task = state.research.as_ref().and_then(|d| d.task.as_deref());
+            if let (Some(plan_task), Some(research_task)) = (plan.task.as_deref(), research_task) {
+                if plan_task != research_task {
+                    problems.push(format!(
+                        "Plan is for task {} but {} is for task {}",
+                        plan_task, path, research_task
+                    ));
+                }
+            }
+        }
+        Some(_) => {}
+        None if body.contains("active-research.md") => {}
+        None => problems.push(format!(
+            "Plan doesn't reference its research (add `research: {}` to the frontmatter)",
+            research_path
+        )),
+    }
+
+    let template = read_template(repo_root, DocKind::Plan).unwrap_or_default();
+    for heading in REQUIRED_PLAN_SECTIONS {
+        let Some(lines) = section(body, heading) else {
+            problems.push(format!("Plan has no `## {}` section", heading));
+            continue;
+        };
+        let placeholders = section(&template, heading).unwrap_or_default();
+        let filled = lines.iter().map(|l| l.trim()).any(|line| {
+            !line.is_empty()
+                && !line.starts_with('#')
+                && line != "..."
+                && !placeholders.iter().any(|p| p.trim() == line)
+        });
+        if !filled {
+            problems.push(format!("`## {}` is empty or still the template", heading));
+        }
+    }
+
+    problems
+}
+
+/// Lines under a `## heading`, up to the next second-level heading
+fn section<'a>(body: &'a str, heading: &str) -> Option<Vec<&'a str>> {
+    let mut lines = body.lines();
+    lines.find(|l| l.trim_end().strip_prefix("## ") == Some(heading))?;
+    Some(lines.take_while(|l| !l.starts_with("## ")).collect())
+}
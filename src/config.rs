@@ -0,0 +1,163 @@
+use crate::embed::Category;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use thiserror::Error;
+
+/// Project settings file at the repository root
+pub const CONFIG_FILE: &str = ".ai-init.toml";
+
+const HEADER: &str = "# ai-init settings for this repository, written by `ai-init init`.\n\
+# Commit this file so `ai-init init` gives every teammate the same setup.\n\n";
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Invalid {CONFIG_FILE}: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("Failed to write {CONFIG_FILE}: {0}")]
+    Serialize(#[from] toml::ser::Error),
+    #[error("Invalid category in {CONFIG_FILE}: {0}")]
+    InvalidCategory(String),
+    #[error("Invalid hook shell in {CONFIG_FILE}: {0}. Valid: native, powershell, sh")]
+    InvalidHookShell(String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// How init treats `.gitignore`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GitignoreMode {
+    /// Add missing entries to an existing .gitignore
+    #[default]
+    Update,
+    /// Add missing entries, creating .gitignore if needed
+    Create,
+    /// Leave .gitignore alone
+    Skip,
+}
+
+impl GitignoreMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Update => "update",
+            Self::Create => "create",
+            Self::Skip => "skip",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "update" => Some(Self::Update),
+            "create" => Some(Self::Create),
+            "skip" | "none" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+}
+
+/// What to do when a file to install already exists with different content
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictPolicy {
+    /// Ask for each file
+    #[default]
+    Prompt,
+    /// Replace without asking
+    Overwrite,
+    /// Keep the existing file
+    Skip,
+}
+
+impl ConflictPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Prompt => "prompt",
+            Self::Overwrite => "overwrite",
+            Self::Skip => "skip",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "prompt" | "ask" => Some(Self::Prompt),
+            "overwrite" | "force" => Some(Self::Overwrite),
+            "skip" | "keep" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+}
+
+/// Choices recorded in `.ai-init.toml`; command-line flags take precedence
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// AI tools to install for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub targets: Option<Vec<String>>,
+    /// Categories to install (same values as --only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only: Option<Vec<String>>,
+    /// Categories to leave out (same values as --skip)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip: Option<Vec<String>>,
+    /// Template source (same values as --source)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Word budget for AGENTS.md (same as --agents-words)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agents_words: Option<usize>,
+    /// How Cursor hooks run (same values as --hook-shell)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hook_shell: Option<String>,
+    pub gitignore: GitignoreMode,
+    pub conflict: ConflictPolicy,
+    /// Template variables (same as --var key=value)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub vars: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// Settings from the repository's `.ai-init.toml`, or None if it has none
+    pub fn load(repo_root: &Path) -> Result<Option<Self>, ConfigError> {
+        let path = repo_root.join(CONFIG_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path)?;
+        Ok(Some(toml::from_str(&content)?))
+    }
+
+    /// Settings from `.ai-init.toml`, or the defaults
+    pub fn load_or_default(repo_root: &Path) -> Result<Self, ConfigError> {
+        Ok(Self::load(repo_root)?.unwrap_or_default())
+    }
+
+    /// Recorded --only and --skip categories
+    #[allow(clippy::type_complexity)]
+    pub fn categories(
+        &self,
+    ) -> Result<(Option<Vec<Category>>, Option<Vec<Category>>), ConfigError> {
+        let parse = |values: &Option<Vec<String>>| -> Result<Option<Vec<Category>>, ConfigError> {
+            values
+                .as_ref()
+                .map(|values| {
+                    values
+                        .iter()
+                        .map(|v| {
+                            Category::from_str(v)
+                                .ok_or_else(|| ConfigError::InvalidCategory(v.clone()))
+                        })
+                        .collect()
+                })
+                .transpose()
+        };
+        Ok((parse(&self.only)?, parse(&self.skip)?))
+    }
+
+    pub fn save(&self, repo_root: &Path) -> Result<(), ConfigError> {
+        let content = format!("{}{}", HEADER, toml::to_string(self)?);
+        std::fs::write(repo_root.join(CONFIG_FILE), content)?;
+        Ok(())
+    }
+}
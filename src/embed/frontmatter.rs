@@ -0,0 +1,154 @@
+/// A value in a frontmatter block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Scalar(String),
+    List(Vec<String>),
+}
+
+/// YAML-style frontmatter at the top of a markdown file.
+/// Supports the subset used by the templates: `key: value` scalars and `- item` lists.
+#[derive(Debug, Clone, Default)]
+pub struct Frontmatter {
+    entries: Vec<(String, Value)>,
+}
+
+impl Frontmatter {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Scalar value for `key`, or None if missing or empty
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        match self.get(key) {
+            Some(Value::Scalar(s)) if !s.is_empty() => Some(s),
+            _ => None,
+        }
+    }
+
+    /// List value for `key`. Comma-separated scalars are split into items.
+    pub fn get_list(&self, key: &str) -> Vec<String> {
+        match self.get(key) {
+            Some(Value::List(items)) => items.clone(),
+            Some(Value::Scalar(s)) => s
+                .split(',')
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn get_bool(&self, key: &str) -> bool {
+        self.get_str(key).is_some_and(|s| s == "true")
+    }
+}
+
+/// Split content into frontmatter and body.
+/// Returns None for the frontmatter when the content doesn't open with `---`.
+pub fn parse(content: &str) -> (Option<Frontmatter>, &str) {
+    let content = content.trim_start_matches('\u{feff}');
+
+    let mut lines = content.split_inclusive('\n');
+    match lines.next() {
+        Some(first) if first.trim_end() == "---" => {}
+        _ => return (None, content),
+    }
+
+    let mut offset = content.find('\n').map_or(content.len(), |i| i + 1);
+    let mut entries: Vec<(String, Value)> = Vec::new();
+
+    for line in lines {
+        offset += line.len();
+        let trimmed = line.trim_end();
+
+        if trimmed == "---" {
+            return (Some(Frontmatter { entries }), &content[offset..]);
+        }
+
+        // List item belonging to the previous key
+        if let Some(item) = trimmed.trim_start().strip_prefix("- ") {
+            if let Some((_, value)) = entries.last_mut() {
+                let item = unquote(item.trim()).to_string();
+                match value {
+                    Value::List(items) => items.push(item),
+                    Value::Scalar(s) if s.is_empty() => *value = Value::List(vec![item]),
+                    Value::Scalar(_) => {}
+                }
+            }
+            continue;
+        }
+
+        if let Some((key, value)) = trimmed.split_once(':') {
+            entries.push((
+                key.trim().to_string(),
+                Value::Scalar(unquote(value.trim()).to_string()),
+            ));
+        }
+    }
+
+    // Unterminated block: treat the whole file as body
+    (None, content)
+}
+
+/// Set a scalar `key` in the frontmatter, replacing its current value or appending it.
+/// Content without frontmatter gets a new block.
+pub fn set(content: &str, key: &str, value: &str) -> String {
+    let content = content.trim_start_matches('\u{feff}');
+    let line = format!("{}: {}\n", key, quote(value));
+
+    let (Some(_), body) = parse(content) else {
+        return format!("---\n{}---\n\n{}", line, content);
+    };
+    let block = &content[..content.len() - body.len()];
+
+    let mut result = String::new();
+    let mut lines = block.split_inclusive('\n').peekable();
+    let mut replaced = false;
+    while let Some(current) = lines.next() {
+        let is_close = lines.peek().is_none() && !result.is_empty();
+        if is_close && !replaced {
+            result.push_str(&line);
+            replaced = true;
+        }
+        let is_key = current
+            .split_once(':')
+            .is_some_and(|(k, _)| !k.starts_with([' ', '-']) && k.trim() == key);
+        if is_key && !replaced {
+            result.push_str(&line);
+            replaced = true;
+            // Drop list items that belonged to the old value
+            while lines
+                .peek()
+                .is_some_and(|l| l.trim_start().starts_with("- "))
+            {
+                lines.next();
+            }
+            continue;
+        }
+        result.push_str(current);
+    }
+
+    result.push_str(body);
+    result
+}
+
+/// Quote a scalar when it would otherwise read as YAML syntax
+fn quote(value: &str) -> String {
+    let special = value.contains(": ")
+        || value.contains(" #")
+        || value.starts_with(['[', '{', '"', '\'', '-', '*', '&', '!', '|', '>', '%', '@']);
+    if special {
+        format!("\"{}\"", value.replace('"', "\\\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn unquote(s: &str) -> &str {
+    for quote in ['"', '\''] {
+        if s.len() >= 2 && s.starts_with(quote) && s.ends_with(quote) {
+            return &s[1..s.len() - 1];
+        }
+    }
+    s
+}
@@ -0,0 +1,161 @@
+/// Name of an embedded file without directory or extension
+/// (e.g., ".cursor/rules/core-principles.mdc" -> "core-principles")
+pub fn file_stem(path: &str) -> &str {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.split('.').next().unwrap_or(name)
+}
+
+/// First sentence of the first prose paragraph, used as a one-line description
+pub fn summary_line(body: &str) -> String {
+    let paragraph: Vec<&str> = body
+        .lines()
+        .map(str::trim)
+        .skip_while(|l| l.is_empty() || l.starts_with('#'))
+        .take_while(|l| !l.is_empty())
+        .collect();
+    let paragraph = paragraph.join(" ");
+
+    match paragraph.find(". ") {
+        Some(end) => paragraph[..=end].to_string(),
+        None => paragraph,
+    }
+}
+
+/// Whitespace-separated word count
+pub fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// Rough token count (about four characters per token), good enough for budgeting
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Remove the leading `# Title` line and any blockquote lines directly under it
+pub fn strip_title(body: &str) -> &str {
+    let body = body.trim_start();
+    if !body.starts_with("# ") {
+        return body;
+    }
+
+    let mut offset = 0;
+    for (i, line) in body.split_inclusive('\n').enumerate() {
+        let trimmed = line.trim();
+        if i == 0 || trimmed.is_empty() || trimmed.starts_with('>') {
+            offset += line.len();
+        } else {
+            break;
+        }
+    }
+    &body[offset..]
+}
+
+/// Push every heading `levels` deeper (e.g., `#` -> `##`), capped at `######`
+pub fn demote_headings(body: &str, levels: usize) -> String {
+    let mut result = String::with_capacity(body.len() + levels * 8);
+    let mut in_code = false;
+
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        }
+        if !in_code && line.starts_with('#') {
+            let depth = line.chars().take_while(|c| *c == '#').count();
+            result.push_str(&"#".repeat((depth + levels).min(6) - depth));
+        }
+        result.push_str(line);
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Condense markdown to its skeleton: headings, bold lead lines and the head of
+/// each list item. Prose paragraphs, blockquotes and code blocks are dropped,
+/// as are headings left with nothing under them.
+pub fn condense(body: &str) -> String {
+    // (heading level, line); level 0 marks content
+    let mut kept: Vec<(usize, String)> = Vec::new();
+    let mut in_code = false;
+
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code || trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed.starts_with('#') {
+            let depth = trimmed.chars().take_while(|c| *c == '#').count();
+            kept.push((depth, trimmed.to_string()));
+        } else if let Some(item) = list_item(line) {
+            kept.push((0, item));
+        } else if trimmed.starts_with("**") {
+            kept.push((0, trimmed.to_string()));
+        }
+    }
+
+    // Drop headings with no content before the next heading of the same or higher level
+    let mut result = String::new();
+    for (i, (level, line)) in kept.iter().enumerate() {
+        if *level > 0 {
+            let has_content = kept[i + 1..]
+                .iter()
+                .take_while(|(l, _)| *l == 0 || *l > *level)
+                .any(|(l, _)| *l == 0);
+            if !has_content {
+                continue;
+            }
+            if !result.is_empty() && !result.ends_with("\n\n") {
+                result.push('\n');
+            }
+            result.push_str(line);
+            result.push_str("\n\n");
+        } else {
+            // Bold lead lines start their own paragraph
+            if line.starts_with("**") && !result.is_empty() && !result.ends_with("\n\n") {
+                result.push('\n');
+            }
+            result.push_str(line);
+            result.push('\n');
+        }
+    }
+
+    result
+}
+
+/// Shorten a list item to its head: the bold lead if it has one, otherwise its first sentence
+fn list_item(line: &str) -> Option<String> {
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let trimmed = line.trim_start();
+
+    let (marker, text) = if let Some(text) = trimmed.strip_prefix("- ") {
+        ("-".to_string(), text)
+    } else {
+        let digits: String = trimmed.chars().take_while(|c| c.is_ascii_digit()).collect();
+        let text = trimmed[digits.len()..].strip_prefix(". ")?;
+        if digits.is_empty() {
+            return None;
+        }
+        (format!("{}.", digits), text)
+    };
+
+    let bold_end = text
+        .strip_prefix("**")
+        .and_then(|rest| rest.find("**"))
+        .map(|end| end + 4);
+
+    let head = match bold_end {
+        // "**Label**: detail" reads as one unit, keep its first sentence
+        Some(end) if !text[end..].starts_with(':') => &text[..end],
+        _ => match text.find(". ") {
+            Some(end) => &text[..=end],
+            None => text,
+        },
+    };
+
+    Some(format!("{}{} {}", indent, marker, head.trim_end()))
+}
@@ -1,205 +1,361 @@
-/// Embedded file with metadata
-pub struct EmbeddedFile {
-    /// Relative path from repo root (e.g., ".ai/ai-context.md")
-    pub target_path: &'static str,
-    /// File content embedded at compile time
-    pub content: &'static str,
-    /// Category for --only/--skip filtering
-    pub category: FileCategory,
+pub mod frontmatter;
+pub mod markdown;
+pub mod source;
+pub mod template;
+
+use crate::stack::{Language, Stack};
+use std::borrow::Cow;
+
+/// Tool-neutral piece of embedded workflow content.
+/// Targets decide where and in which format each artifact is written.
+pub struct Artifact {
+    /// Path within the artifact kind (e.g., "core-principles.mdc", "code-review/SKILL.md")
+    pub name: Cow<'static, str>,
+    /// File content, embedded at compile time or loaded from a template source
+    pub content: Cow<'static, str>,
+    /// Kind for --only/--skip filtering and target mapping
+    pub kind: ArtifactKind,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum FileCategory {
-    AiTemplates, // .ai/ files
-    Rules,       // .cursor/rules/
-    Commands,    // .cursor/commands/
-    Hooks,       // .cursor/hooks/
-    Skills,      // .cursor/skills/
-    Agents,      // .cursor/agents/
+pub enum ArtifactKind {
+    Context, // shared project context and templates (.ai/)
+    Rule,    // always-on or glob-scoped instructions
+    Command, // user-invoked prompts
+    Hook,    // editor lifecycle scripts
+    Skill,   // on-demand expertise
+    Agent,   // specialized subagent personas
 }
 
-impl FileCategory {
-    #[allow(dead_code)]
+impl ArtifactKind {
     pub fn as_str(&self) -> &'static str {
         match self {
-            Self::AiTemplates => "ai",
-            Self::Rules => "rules",
-            Self::Commands => "commands",
-            Self::Hooks => "hooks",
-            Self::Skills => "skills",
-            Self::Agents => "agents",
+            Self::Context => "ai",
+            Self::Rule => "rules",
+            Self::Command => "commands",
+            Self::Hook => "hooks",
+            Self::Skill => "skills",
+            Self::Agent => "agents",
         }
     }
 
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
-            "ai" => Some(Self::AiTemplates),
-            "rules" => Some(Self::Rules),
-            "commands" => Some(Self::Commands),
-            "hooks" => Some(Self::Hooks),
-            "skills" => Some(Self::Skills),
-            "agents" => Some(Self::Agents),
+            "ai" | "context" => Some(Self::Context),
+            "rules" => Some(Self::Rule),
+            "commands" => Some(Self::Command),
+            "hooks" => Some(Self::Hook),
+            "skills" => Some(Self::Skill),
+            "agents" => Some(Self::Agent),
             _ => None,
         }
     }
 }
 
-/// Returns all embedded files
-pub fn get_all_files() -> Vec<EmbeddedFile> {
+/// Returns the core embedded artifacts; language rule packs come from `get_rule_packs`
+pub fn get_all_artifacts() -> Vec<Artifact> {
     vec![
-        // .ai/ files
-        EmbeddedFile {
-            target_path: ".ai/ai-context.md",
-            content: include_str!("../../public/ai/ai-context.md"),
-            category: FileCategory::AiTemplates,
-        },
-        EmbeddedFile {
-            target_path: ".ai/ai-guardrails.md",
-            content: include_str!("../../public/ai/ai-guardrails.md"),
-            category: FileCategory::AiTemplates,
-        },
-        EmbeddedFile {
-            target_path: ".ai/template/decision.md",
-            content: include_str!("../../public/ai/template/decision.md"),
-            category: FileCategory::AiTemplates,
-        },
-        EmbeddedFile {
-            target_path: ".ai/template/plan.md",
-            content: include_str!("../../public/ai/template/plan.md"),
-            category: FileCategory::AiTemplates,
-        },
-        EmbeddedFile {
-            target_path: ".ai/template/research.md",
-            content: include_str!("../../public/ai/template/research.md"),
-            category: FileCategory::AiTemplates,
-        },
-        // .cursor/rules/
-        EmbeddedFile {
-            target_path: ".cursor/rules/ai-guidelines.mdc",
-            content: include_str!("../../public/cursor/rules/ai-guidelines.mdc"),
-            category: FileCategory::Rules,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/rules/core-principles.mdc",
-            content: include_str!("../../public/cursor/rules/core-principles.mdc"),
-            category: FileCategory::Rules,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/rules/security-patterns.mdc",
-            content: include_str!("../../public/cursor/rules/security-patterns.mdc"),
-            category: FileCategory::Rules,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/rules/three-phase-workflow.mdc",
-            content: include_str!("../../public/cursor/rules/three-phase-workflow.mdc"),
-            category: FileCategory::Rules,
-        },
-        // .cursor/commands/
-        EmbeddedFile {
-            target_path: ".cursor/commands/adr.md",
-            content: include_str!("../../public/cursor/commands/adr.md"),
-            category: FileCategory::Commands,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/commands/commit.md",
-            content: include_str!("../../public/cursor/commands/commit.md"),
-            category: FileCategory::Commands,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/commands/implement.md",
-            content: include_str!("../../public/cursor/commands/implement.md"),
-            category: FileCategory::Commands,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/commands/plan.md",
-            content: include_str!("../../public/cursor/commands/plan.md"),
-            category: FileCategory::Commands,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/commands/research.md",
-            content: include_str!("../../public/cursor/commands/research.md"),
-            category: FileCategory::Commands,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/commands/review.md",
-            content: include_str!("../../public/cursor/commands/review.md"),
-            category: FileCategory::Commands,
-        },
-        // .cursor/hooks/
-        EmbeddedFile {
-            target_path: ".cursor/hooks.json",
-            content: include_str!("../../public/cursor/hooks.json"),
-            category: FileCategory::Hooks,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/hooks/extract-learnings.ps1",
-            content: include_str!("../../public/cursor/hooks/extract-learnings.ps1"),
-            category: FileCategory::Hooks,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/hooks/inject-context.ps1",
-            content: include_str!("../../public/cursor/hooks/inject-context.ps1"),
-            category: FileCategory::Hooks,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/hooks/post-edit-check.ps1",
-            content: include_str!("../../public/cursor/hooks/post-edit-check.ps1"),
-            category: FileCategory::Hooks,
-        },
-        // .cursor/agents/
-        EmbeddedFile {
-            target_path: ".cursor/agents/researcher.md",
-            content: include_str!("../../public/cursor/agents/researcher.md"),
-            category: FileCategory::Agents,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/agents/verifier.md",
-            content: include_str!("../../public/cursor/agents/verifier.md"),
-            category: FileCategory::Agents,
-        },
-        // .cursor/skills/
-        EmbeddedFile {
-            target_path: ".cursor/skills/architecture-decision/SKILL.md",
-            content: include_str!("../../public/cursor/skills/architecture-decision/SKILL.md"),
-            category: FileCategory::Skills,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/skills/code-review/SKILL.md",
-            content: include_str!("../../public/cursor/skills/code-review/SKILL.md"),
-            category: FileCategory::Skills,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/skills/research-methodology/SKILL.md",
-            content: include_str!("../../public/cursor/skills/research-methodology/SKILL.md"),
-            category: FileCategory::Skills,
-        },
-        EmbeddedFile {
-            target_path: ".cursor/skills/security-audit/SKILL.md",
-            content: include_str!("../../public/cursor/skills/security-audit/SKILL.md"),
-            category: FileCategory::Skills,
+        // Context
+        Artifact {
+            name: Cow::Borrowed("ai-context.md"),
+            content: Cow::Borrowed(include_str!("../../public/ai/ai-context.md")),
+            kind: ArtifactKind::Context,
+        },
+        Artifact {
+            name: Cow::Borrowed("ai-summary.md"),
+            content: Cow::Borrowed(include_str!("../../public/ai/ai-summary.md")),
+            kind: ArtifactKind::Context,
+        },
+        Artifact {
+            name: Cow::Borrowed("ai-guardrails.md"),
+            content: Cow::Borrowed(include_str!("../../public/ai/ai-guardrails.md")),
+            kind: ArtifactKind::Context,
+        },
+        Artifact {
+            name: Cow::Borrowed("template/decision.md"),
+            content: Cow::Borrowed(include_str!("../../public/ai/template/decision.md")),
+            kind: ArtifactKind::Context,
+        },
+        Artifact {
+            name: Cow::Borrowed("template/plan.md"),
+            content: Cow::Borrowed(include_str!("../../public/ai/template/plan.md")),
+            kind: ArtifactKind::Context,
+        },
+        Artifact {
+            name: Cow::Borrowed("template/research.md"),
+            content: Cow::Borrowed(include_str!("../../public/ai/template/research.md")),
+            kind: ArtifactKind::Context,
+        },
+        // Rules
+        Artifact {
+            name: Cow::Borrowed("ai-guidelines.mdc"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/rules/ai-guidelines.mdc")),
+            kind: ArtifactKind::Rule,
+        },
+        Artifact {
+            name: Cow::Borrowed("core-principles.mdc"),
+            content: Cow::Borrowed(include_str!(
+                "../../public/cursor/rules/core-principles.mdc"
+            )),
+            kind: ArtifactKind::Rule,
+        },
+        Artifact {
+            name: Cow::Borrowed("security-patterns.mdc"),
+            content: Cow::Borrowed(include_str!(
+                "../../public/cursor/rules/security-patterns.mdc"
+            )),
+            kind: ArtifactKind::Rule,
+        },
+        Artifact {
+            name: Cow::Borrowed("three-phase-workflow.mdc"),
+            content: Cow::Borrowed(include_str!(
+                "../../public/cursor/rules/three-phase-workflow.mdc"
+            )),
+            kind: ArtifactKind::Rule,
+        },
+        // Commands
+        Artifact {
+            name: Cow::Borrowed("adr.md"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/commands/adr.md")),
+            kind: ArtifactKind::Command,
+        },
+        Artifact {
+            name: Cow::Borrowed("commit.md"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/commands/commit.md")),
+            kind: ArtifactKind::Command,
+        },
+        Artifact {
+            name: Cow::Borrowed("implement.md"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/commands/implement.md")),
+            kind: ArtifactKind::Command,
+        },
+        Artifact {
+            name: Cow::Borrowed("plan.md"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/commands/plan.md")),
+            kind: ArtifactKind::Command,
+        },
+        Artifact {
+            name: Cow::Borrowed("research.md"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/commands/research.md")),
+            kind: ArtifactKind::Command,
+        },
+        Artifact {
+            name: Cow::Borrowed("review.md"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/commands/review.md")),
+            kind: ArtifactKind::Command,
+        },
+        // Hooks
+        Artifact {
+            name: Cow::Borrowed("hooks.json"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/hooks.json")),
+            kind: ArtifactKind::Hook,
+        },
+        Artifact {
+            name: Cow::Borrowed("extract-learnings.ps1"),
+            content: Cow::Borrowed(include_str!(
+                "../../public/cursor/hooks/extract-learnings.ps1"
+            )),
+            kind: ArtifactKind::Hook,
+        },
+        Artifact {
+            name: Cow::Borrowed("inject-context.ps1"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/hooks/inject-context.ps1")),
+            kind: ArtifactKind::Hook,
+        },
+        Artifact {
+            name: Cow::Borrowed("post-edit-check.ps1"),
+            content: Cow::Borrowed(include_str!(
+                "../../public/cursor/hooks/post-edit-check.ps1"
+            )),
+            kind: ArtifactKind::Hook,
+        },
+        Artifact {
+            name: Cow::Borrowed("extract-learnings.sh"),
+            content: Cow::Borrowed(include_str!(
+                "../../public/cursor/hooks/extract-learnings.sh"
+            )),
+            kind: ArtifactKind::Hook,
+        },
+        Artifact {
+            name: Cow::Borrowed("inject-context.sh"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/hooks/inject-context.sh")),
+            kind: ArtifactKind::Hook,
+        },
+        Artifact {
+            name: Cow::Borrowed("post-edit-check.sh"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/hooks/post-edit-check.sh")),
+            kind: ArtifactKind::Hook,
+        },
+        // Agents
+        Artifact {
+            name: Cow::Borrowed("researcher.md"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/agents/researcher.md")),
+            kind: ArtifactKind::Agent,
+        },
+        Artifact {
+            name: Cow::Borrowed("verifier.md"),
+            content: Cow::Borrowed(include_str!("../../public/cursor/agents/verifier.md")),
+            kind: ArtifactKind::Agent,
+        },
+        // Skills
+        Artifact {
+            name: Cow::Borrowed("architecture-decision/SKILL.md"),
+            content: Cow::Borrowed(include_str!(
+                "../../public/cursor/skills/architecture-decision/SKILL.md"
+            )),
+            kind: ArtifactKind::Skill,
+        },
+        Artifact {
+            name: Cow::Borrowed("code-review/SKILL.md"),
+            content: Cow::Borrowed(include_str!(
+                "../../public/cursor/skills/code-review/SKILL.md"
+            )),
+            kind: ArtifactKind::Skill,
+        },
+        Artifact {
+            name: Cow::Borrowed("research-methodology/SKILL.md"),
+            content: Cow::Borrowed(include_str!(
+                "../../public/cursor/skills/research-methodology/SKILL.md"
+            )),
+            kind: ArtifactKind::Skill,
+        },
+        Artifact {
+            name: Cow::Borrowed("security-audit/SKILL.md"),
+            content: Cow::Borrowed(include_str!(
+                "../../public/cursor/skills/security-audit/SKILL.md"
+            )),
+            kind: ArtifactKind::Skill,
         },
     ]
 }
 
-/// Get files filtered by category
-pub fn get_files_by_categories(
-    only: Option<&[FileCategory]>,
-    skip: Option<&[FileCategory]>,
-) -> Vec<EmbeddedFile> {
-    get_all_files()
-        .into_iter()
-        .filter(|f| {
-            if let Some(only_cats) = only {
-                if !only_cats.contains(&f.category) {
-                    return false;
-                }
+/// Language-specific rules, installed when the language is detected or selected
+pub fn get_rule_packs() -> Vec<(Language, Artifact)> {
+    vec![
+        (
+            Language::Rust,
+            Artifact {
+                name: Cow::Borrowed("rust.mdc"),
+                content: Cow::Borrowed(include_str!("../../public/cursor/rules/lang/rust.mdc")),
+                kind: ArtifactKind::Rule,
+            },
+        ),
+        (
+            Language::TypeScript,
+            Artifact {
+                name: Cow::Borrowed("typescript.mdc"),
+                content: Cow::Borrowed(include_str!(
+                    "../../public/cursor/rules/lang/typescript.mdc"
+                )),
+                kind: ArtifactKind::Rule,
+            },
+        ),
+        (
+            Language::Python,
+            Artifact {
+                name: Cow::Borrowed("python.mdc"),
+                content: Cow::Borrowed(include_str!("../../public/cursor/rules/lang/python.mdc")),
+                kind: ArtifactKind::Rule,
+            },
+        ),
+        (
+            Language::Go,
+            Artifact {
+                name: Cow::Borrowed("go.mdc"),
+                content: Cow::Borrowed(include_str!("../../public/cursor/rules/lang/go.mdc")),
+                kind: ArtifactKind::Rule,
+            },
+        ),
+    ]
+}
+
+/// An --only/--skip value: an artifact kind, all rule packs, or one language's pack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Kind(ArtifactKind),
+    Packs,
+    Pack(Language),
+}
+
+impl Category {
+    pub fn from_str(s: &str) -> Option<Self> {
+        if let Some(kind) = ArtifactKind::from_str(s) {
+            return Some(Self::Kind(kind));
+        }
+        match s.to_lowercase().as_str() {
+            "lang" | "langs" | "packs" => Some(Self::Packs),
+            other => {
+                let language = Language::from_str(other)?;
+                get_rule_packs()
+                    .iter()
+                    .any(|(l, _)| *l == language)
+                    .then_some(Self::Pack(language))
             }
-            if let Some(skip_cats) = skip {
-                if skip_cats.contains(&f.category) {
-                    return false;
+        }
+    }
+
+    /// Whether the category covers `language`'s pack: packs are rules too
+    fn selects_pack(&self, language: Language) -> bool {
+        matches!(self, Self::Packs | Self::Kind(ArtifactKind::Rule))
+            || *self == Self::Pack(language)
+    }
+}
+
+/// A complete template set: core artifacts plus language rule packs
+pub struct Templates {
+    pub core: Vec<Artifact>,
+    pub packs: Vec<(Language, Artifact)>,
+}
+
+impl Templates {
+    /// Templates compiled into the binary
+    pub fn embedded() -> Self {
+        Self {
+            core: get_all_artifacts(),
+            packs: get_rule_packs(),
+        }
+    }
+
+    /// Every artifact, including all rule packs
+    pub fn every(self) -> Vec<Artifact> {
+        let mut artifacts = self.core;
+        artifacts.extend(self.packs.into_iter().map(|(_, artifact)| artifact));
+        artifacts
+    }
+
+    /// Artifacts selected by --only/--skip.
+    /// Rule packs count as rules and follow the detected stack unless --only names them.
+    pub fn select(
+        self,
+        only: Option<&[Category]>,
+        skip: Option<&[Category]>,
+        stack: &Stack,
+    ) -> Vec<Artifact> {
+        let skipped = |c: Category| skip.is_some_and(|s| s.contains(&c));
+
+        let mut artifacts: Vec<Artifact> = self
+            .core
+            .into_iter()
+            .filter(|a| only.is_none_or(|o| o.contains(&Category::Kind(a.kind))))
+            .filter(|a| !skipped(Category::Kind(a.kind)))
+            .collect();
+
+        for (language, artifact) in self.packs {
+            let selected = match only {
+                // `--only lang` or `--only rules` installs the detected packs, `--only rust` that pack
+                Some(o) => {
+                    o.contains(&Category::Pack(language))
+                        || (stack.languages.contains(&language)
+                            && o.iter().any(|c| c.selects_pack(language)))
                 }
+                None => stack.languages.contains(&language),
+            };
+            let excluded = skip.is_some_and(|s| s.iter().any(|c| c.selects_pack(language)));
+            if selected && !excluded {
+                artifacts.push(artifact);
             }
-            true
-        })
-        .collect()
+        }
+
+        artifacts
+    }
 }
@@ -0,0 +1,371 @@
+use super::{Artifact, ArtifactKind, Templates};
+use crate::stack::Language;
+use std::borrow::Cow;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SourceError {
+    #[error("Template source not found: {0}")]
+    NotFound(String),
+    #[error("No templates found in {0}. Expected the public/ layout (ai/, cursor/rules/, ...)")]
+    Empty(String),
+    #[error(
+        "Unsafe path in template source: {0}. Paths must be relative and stay inside the layout"
+    )]
+    UnsafePath(String),
+    #[error("Invalid git source: {0}. URLs and refs must not start with '-'")]
+    InvalidGit(String),
+    #[error("Failed to clone {0}: {1}")]
+    Clone(String, String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Where templates come from: the embedded set, or a fork with the same layout as `public/`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Embedded,
+    /// Local directory, either the layout itself or a checkout with `public/`
+    Dir(PathBuf),
+    /// `.tar.gz` or `.tgz` archive of the layout
+    Archive(PathBuf),
+    /// Git repository cloned at an optional branch or tag (`git+<url>#<ref>`)
+    Git {
+        url: String,
+        reference: Option<String>,
+    },
+}
+
+impl Source {
+    /// Parse a --source value. Without one, the embedded templates are used.
+    pub fn parse(value: Option<&str>) -> Self {
+        let Some(value) = value else {
+            return Self::Embedded;
+        };
+
+        if value == "embedded" {
+            Self::Embedded
+        } else if let Some(url) = value.strip_prefix("git+") {
+            let (url, reference) = match url.rsplit_once('#') {
+                Some((url, reference)) => (url, Some(reference.to_string())),
+                None => (url, None),
+            };
+            Self::Git {
+                url: url.to_string(),
+                reference,
+            }
+        } else if value.ends_with(".git") || value.starts_with("git@") {
+            Self::Git {
+                url: value.to_string(),
+                reference: None,
+            }
+        } else if value.ends_with(".tar.gz") || value.ends_with(".tgz") {
+            Self::Archive(PathBuf::from(value))
+        } else {
+            Self::Dir(PathBuf::from(value))
+        }
+    }
+
+    pub fn is_embedded(&self) -> bool {
+        matches!(self, Self::Embedded)
+    }
+
+    /// Short description for progress output
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Embedded => "embedded".to_string(),
+            Self::Dir(path) | Self::Archive(path) => path.display().to_string(),
+            Self::Git { url, reference } => match reference {
+                Some(reference) => format!("{}#{}", url, reference),
+                None => url.clone(),
+            },
+        }
+    }
+
+    /// Read the template set
+    pub fn load(&self) -> Result<Templates, SourceError> {
+        let files = match self {
+            Self::Embedded => return Ok(Templates::embedded()),
+            Self::Dir(path) => {
+                if !path.is_dir() {
+                    return Err(SourceError::NotFound(path.display().to_string()));
+                }
+                read_dir(path)?
+            }
+            Self::Archive(path) => {
+                if !path.is_file() {
+                    return Err(SourceError::NotFound(path.display().to_string()));
+                }
+                read_archive(path)?
+            }
+            Self::Git { url, reference } => clone(url, reference.as_deref())?,
+        };
+
+        let templates = from_files(files)?;
+        if templates.core.is_empty() && templates.packs.is_empty() {
+            return Err(SourceError::Empty(self.describe()));
+        }
+        Ok(templates)
+    }
+}
+
+/// Map files laid out like `public/` onto artifacts.
+/// Paths may sit under a common prefix, such as `public/` or an archive's top directory.
+fn from_files(mut files: Vec<(String, String)>) -> Result<Templates, SourceError> {
+    if let Some((path, _)) = files.iter().find(|(path, _)| !is_safe(Path::new(path))) {
+        return Err(SourceError::UnsafePath(path.clone()));
+    }
+    files.sort();
+
+    // The shortest prefix under which the layout starts
+    let root = files
+        .iter()
+        .filter_map(|(path, _)| {
+            ["ai/", "cursor/"]
+                .iter()
+                .filter_map(|dir| {
+                    path.find(dir)
+                        .filter(|i| *i == 0 || path[..*i].ends_with('/'))
+                })
+                .min()
+                .map(|i| path[..i].to_string())
+        })
+        .min_by_key(|prefix| prefix.len())
+        .unwrap_or_default();
+
+    let mut templates = Templates {
+        core: Vec::new(),
+        packs: Vec::new(),
+    };
+
+    for (path, content) in files {
+        let Some(path) = path.strip_prefix(&root) else {
+            continue;
+        };
+        let Some((kind, name)) = classify(path) else {
+            continue;
+        };
+        let artifact = Artifact {
+            name: Cow::Owned(name.to_string()),
+            content: Cow::Owned(content),
+            kind,
+        };
+
+        let pack = path
+            .strip_prefix("cursor/rules/lang/")
+            .and_then(|name| Language::from_str(super::markdown::file_stem(name)));
+        match pack {
+            Some(language) => templates.packs.push((language, artifact)),
+            None => templates.core.push(artifact),
+        }
+    }
+
+    Ok(templates)
+}
+
+/// Relative, without `..`, root or drive prefix components
+fn is_safe(path: &Path) -> bool {
+    path.components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+/// Artifact kind and name for a path relative to the layout root
+fn classify(path: &str) -> Option<(ArtifactKind, &str)> {
+    if let Some(name) = path.strip_prefix("ai/") {
+        return Some((ArtifactKind::Context, name));
+    }
+    let path = path.strip_prefix("cursor/")?;
+    if path == "hooks.json" {
+        return Some((ArtifactKind::Hook, path));
+    }
+
+    let (dir, name) = path.split_once('/')?;
+    let kind = match dir {
+        "rules" => ArtifactKind::Rule,
+        "commands" => ArtifactKind::Command,
+        "hooks" => ArtifactKind::Hook,
+        "agents" => ArtifactKind::Agent,
+        "skills" => ArtifactKind::Skill,
+        _ => return None,
+    };
+    // Rule packs keep the flat name the embedded set uses
+    let name = name.strip_prefix("lang/").unwrap_or(name);
+    (!name.contains('/') || kind == ArtifactKind::Skill).then_some((kind, name))
+}
+
+/// Every UTF-8 file under `dir`, keyed by its `/`-separated relative path
+pub fn read_dir(dir: &Path) -> Result<Vec<(String, String)>, SourceError> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        for entry in std::fs::read_dir(&current)? {
+            let entry = entry?;
+            let path = entry.path();
+            // Symlinks could loop or reach outside the tree
+            let file_type = entry.file_type()?;
+            if file_type.is_symlink() || path.file_name().is_some_and(|n| n == ".git") {
+                continue;
+            }
+            if file_type.is_dir() {
+                pending.push(path);
+            } else if let Ok(content) = std::fs::read_to_string(&path) {
+                let relative = path.strip_prefix(dir).unwrap_or(&path);
+                let relative: Vec<String> = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                    .collect();
+                files.push((relative.join("/"), content));
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Every UTF-8 file in a gzipped tarball
+fn read_archive(path: &Path) -> Result<Vec<(String, String)>, SourceError> {
+    let file = std::fs::File::open(path)?;
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(file));
+    let mut files = Vec::new();
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let entry_path = entry.path()?;
+        let name = entry_path.to_string_lossy().into_owned();
+        if !is_safe(&entry_path) {
+            return Err(SourceError::UnsafePath(name));
+        }
+        let mut content = String::new();
+        if entry.read_to_string(&mut content).is_ok() {
+            files.push((name.trim_start_matches("./").to_string(), content));
+        }
+    }
+
+    Ok(files)
+}
+
+/// Shallow-clone a repository into a temporary directory and read it
+fn clone(url: &str, reference: Option<&str>) -> Result<Vec<(String, String)>, SourceError> {
+    // A value from a committed config must never be read as a git option
+    if url.starts_with('-') || reference.is_some_and(|r| r.starts_with('-')) {
+        return Err(SourceError::InvalidGit(
+            Source::Git {
+                url: url.to_string(),
+                reference: reference.map(str::to_string),
+            }
+            .describe(),
+        ));
+    }
+
+    // Removed when dropped
+    let dir = tempfile::Builder::new()
+        .prefix("ai-init-source-")
+        .tempdir()?;
+    let checkout = dir.path().join("checkout");
+
+    let mut command = Command::new("git");
+    command.args(["clone", "--quiet", "--depth", "1"]);
+    if let Some(reference) = reference {
+        command.args(["--branch", reference]);
+    }
+    let output = command
+        .arg("--")
+        .arg(url)
+        .arg(&checkout)
+        .output()
+        .map_err(|e| SourceError::Clone(url.to_string(), e.to_string()))?;
+    if !output.status.success() {
+        return Err(SourceError::Clone(
+            url.to_string(),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    read_dir(&checkout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_safe_rejects_paths_leaving_the_layout() {
+        assert!(is_safe(Path::new("ai/ai-context.md")));
+        assert!(is_safe(Path::new("./cursor/rules/a.mdc")));
+        assert!(!is_safe(Path::new("../ai/ai-context.md")));
+        assert!(!is_safe(Path::new("ai/../../etc/passwd")));
+        assert!(!is_safe(Path::new("/etc/passwd")));
+    }
+
+    #[test]
+    fn classify_maps_layout_paths_to_artifacts() {
+        assert_eq!(
+            classify("ai/ai-context.md"),
+            Some((ArtifactKind::Context, "ai-context.md"))
+        );
+        assert_eq!(
+            classify("cursor/rules/lang/rust.mdc"),
+            Some((ArtifactKind::Rule, "rust.mdc"))
+        );
+        assert_eq!(
+            classify("cursor/skills/review/SKILL.md"),
+            Some((ArtifactKind::Skill, "review/SKILL.md"))
+        );
+        assert_eq!(classify("cursor/rules/nested/deep.mdc"), None);
+        assert_eq!(classify("cursor/other/file.md"), None);
+        assert_eq!(classify("README.md"), None);
+    }
+
+    #[test]
+    fn from_files_rejects_parent_paths() {
+        let files = vec![
+            ("ai/ai-context.md".to_string(), "# Context".to_string()),
+            ("ai/../../outside.md".to_string(), "escaped".to_string()),
+        ];
+        assert!(matches!(
+            from_files(files),
+            Err(SourceError::UnsafePath(path)) if path == "ai/../../outside.md"
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_dir_skips_symlinks() {
+        let outside = tempfile::tempdir().unwrap();
+        std::fs::write(outside.path().join("secret.md"), "secret").unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("ai")).unwrap();
+        std::fs::write(dir.path().join("ai/ai-context.md"), "# Context").unwrap();
+        std::os::unix::fs::symlink(outside.path(), dir.path().join("ai/linked")).unwrap();
+        std::os::unix::fs::symlink(outside.path().join("secret.md"), dir.path().join("ai/s.md"))
+            .unwrap();
+        // A loop back to the root must not recurse forever
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("ai/loop")).unwrap();
+
+        let files = read_dir(dir.path()).unwrap();
+        assert_eq!(
+            files,
+            vec![("ai/ai-context.md".to_string(), "# Context".to_string())]
+        );
+    }
+
+    #[test]
+    fn clone_rejects_option_like_values() {
+        assert!(matches!(
+            clone("--upload-pack=touch pwned", None),
+            Err(SourceError::InvalidGit(_))
+        ));
+        assert!(matches!(
+            clone("https://example.com/t.git", Some("--upload-pack=x")),
+            Err(SourceError::InvalidGit(_))
+        ));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::stack::Stack;
+use std::path::Path;
+
+/// Values for `{{name}}` placeholders in embedded files, filled in at install time
+#[derive(Debug, Clone, Default)]
+pub struct Vars {
+    entries: Vec<(String, String)>,
+}
+
+impl Vars {
+    /// Built-in variables detected from the repository and its stack:
+    /// `project_name`, `date`, `default_branch`, `primary_language`,
+    /// `stack_overview` and `tooling`
+    pub fn detect(repo_root: &Path, stack: &Stack) -> Self {
+        let mut vars = Self::default();
+        vars.set(
+            "project_name",
+            repo_root
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("project"),
+        );
+        vars.set("date", &chrono::Local::now().format("%Y-%m-%d").to_string());
+        vars.set(
+            "default_branch",
+            &crate::git::default_branch(repo_root).unwrap_or_else(|| "main".to_string()),
+        );
+        vars.set(
+            "primary_language",
+            stack.primary_language().map_or("unknown", |l| l.name()),
+        );
+        vars.set("stack_overview", &stack.overview());
+        vars.set("tooling", &stack.tooling());
+        vars
+    }
+
+    /// Set a variable, replacing any earlier value
+    pub fn set(&mut self, key: &str, value: &str) {
+        match self.entries.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.entries.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Replace `{{name}}` placeholders with their values.
+/// Unknown placeholders are left as written.
+pub fn render(content: &str, vars: &Vars) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let key = rest[start + 2..start + 2 + len].trim();
+        result.push_str(&rest[..start]);
+        match vars.get(key) {
+            Some(value) => result.push_str(value),
+            None => result.push_str(&rest[start..start + len + 4]),
+        }
+        rest = &rest[start + len + 4..];
+    }
+
+    result.push_str(rest);
+    result
+}
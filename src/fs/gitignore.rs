@@ -1,35 +1,32 @@
 use std::fs;
 use std::path::Path;
 
-/// Update .gitignore to include .cursor/ and .ai/ if not present.
-/// Returns true if file was modified.
-pub fn update_gitignore(path: &Path) -> std::io::Result<bool> {
+/// Update .gitignore to include each of `entries` (e.g., ".ai/") if not present.
+/// Returns the entries that were added.
+pub fn update_gitignore<'a>(path: &Path, entries: &[&'a str]) -> std::io::Result<Vec<&'a str>> {
     let content = fs::read_to_string(path)?;
     let mut lines: Vec<&str> = content.lines().collect();
-    let mut modified = false;
+    let missing = missing_gitignore_entries(&content, entries);
 
-    // Check and add .cursor/
-    if !lines
-        .iter()
-        .any(|l| l.trim() == ".cursor/" || l.trim() == ".cursor")
-    {
-        lines.push(".cursor/");
-        modified = true;
-    }
-
-    // Check and add .ai/
-    if !lines
-        .iter()
-        .any(|l| l.trim() == ".ai/" || l.trim() == ".ai")
-    {
-        lines.push(".ai/");
-        modified = true;
-    }
-
-    if modified {
+    if !missing.is_empty() {
+        lines.extend(missing.iter().copied());
         let new_content = lines.join("\n") + "\n";
         fs::write(path, new_content)?;
     }
 
-    Ok(modified)
+    Ok(missing)
+}
+
+/// Entries not yet ignored, accepting both "dir/" and "dir" forms
+pub fn missing_gitignore_entries<'a>(content: &str, entries: &[&'a str]) -> Vec<&'a str> {
+    entries
+        .iter()
+        .filter(|entry| {
+            let bare = entry.trim_end_matches('/');
+            !content
+                .lines()
+                .any(|l| l.trim() == **entry || l.trim() == bare)
+        })
+        .copied()
+        .collect()
 }
@@ -0,0 +1,76 @@
+use super::CopyResult;
+use std::fs;
+use std::path::Path;
+
+pub const BLOCK_BEGIN: &str = "<!-- ai-init:begin -->";
+const BLOCK_END: &str = "<!-- ai-init:end -->";
+
+/// Write `block` between ai-init markers in the target file.
+/// Content outside the markers is preserved. Files without markers get the
+/// block appended, and new files are created as `header` followed by the block.
+pub fn write_managed_block(
+    target: &Path,
+    header: &str,
+    block: &str,
+) -> std::io::Result<CopyResult> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let managed = format!("{}\n{}\n{}\n", BLOCK_BEGIN, block.trim_end(), BLOCK_END);
+
+    if !target.exists() {
+        fs::write(target, format!("{}{}", header, managed))?;
+        return Ok(CopyResult::Created);
+    }
+
+    let existing = fs::read_to_string(target)?;
+    let updated = match (existing.find(BLOCK_BEGIN), existing.find(BLOCK_END)) {
+        (Some(start), Some(end)) if start < end => {
+            let mut end = end + BLOCK_END.len();
+            if existing[end..].starts_with('\n') {
+                end += 1;
+            }
+            format!("{}{}{}", &existing[..start], managed, &existing[end..])
+        }
+        _ => format!("{}\n\n{}", existing.trim_end(), managed),
+    };
+
+    if updated == existing {
+        return Ok(CopyResult::Skipped);
+    }
+
+    fs::write(target, updated)?;
+    Ok(CopyResult::Overwritten)
+}
+
+/// Contents of the ai-init section, without the markers
+pub fn read_managed_block(target: &Path) -> Option<String> {
+    let existing = fs::read_to_string(target).ok()?;
+    let start = existing.find(BLOCK_BEGIN)? + BLOCK_BEGIN.len();
+    let end = existing.find(BLOCK_END)?;
+    (start <= end).then(|| existing[start..end].trim().to_string())
+}
+
+/// Remove the ai-init section from the target file, deleting the file when
+/// nothing but `header` is left. Returns false if there was no section.
+pub fn remove_managed_block(target: &Path, header: &str) -> std::io::Result<bool> {
+    let existing = match fs::read_to_string(target) {
+        Ok(content) => content,
+        Err(_) => return Ok(false),
+    };
+
+    let (start, end) = match (existing.find(BLOCK_BEGIN), existing.find(BLOCK_END)) {
+        (Some(start), Some(end)) if start < end => (start, end + BLOCK_END.len()),
+        _ => return Ok(false),
+    };
+
+    let remaining = format!("{}{}", &existing[..start], &existing[end..]);
+    if remaining.trim().is_empty() || remaining.trim() == header.trim() {
+        fs::remove_file(target)?;
+    } else {
+        fs::write(target, remaining.trim().to_string() + "\n")?;
+    }
+
+    Ok(true)
+}
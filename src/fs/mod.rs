@@ -1,5 +1,9 @@
 mod copy;
 mod gitignore;
+mod managed;
+mod remove;
 
 pub use copy::{copy_with_prompt, CopyResult};
-pub use gitignore::update_gitignore;
+pub use gitignore::{missing_gitignore_entries, update_gitignore};
+pub use managed::{read_managed_block, remove_managed_block, write_managed_block, BLOCK_BEGIN};
+pub use remove::remove_with_empty_parents;
@@ -0,0 +1,21 @@
+use std::fs;
+use std::path::Path;
+
+/// Remove a file, then any parent directories it leaves empty, stopping at `root`
+pub fn remove_with_empty_parents(path: &Path, root: &Path) -> std::io::Result<()> {
+    fs::remove_file(path)?;
+
+    let mut dir = path.parent();
+    while let Some(current) = dir {
+        if current == root || !current.starts_with(root) {
+            break;
+        }
+        if fs::read_dir(current)?.next().is_some() {
+            break;
+        }
+        fs::remove_dir(current)?;
+        dir = current.parent();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use std::path::Path;
+
+/// Default branch of the repository: the branch `origin/HEAD` points at,
+/// else the currently checked-out branch
+pub fn default_branch(repo_root: &Path) -> Option<String> {
+    let git_dir = repo_root.join(".git");
+
+    ["refs/remotes/origin/HEAD", "HEAD"]
+        .iter()
+        .find_map(|file| {
+            let head = std::fs::read_to_string(git_dir.join(file)).ok()?;
+            let reference = head.trim().strip_prefix("ref: ")?;
+            reference
+                .strip_prefix("refs/remotes/origin/")
+                .or_else(|| reference.strip_prefix("refs/heads/"))
+                .map(str::to_string)
+        })
+}
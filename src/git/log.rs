@@ -0,0 +1,31 @@
+use std::path::Path;
+use std::process::Command;
+
+/// Abbreviated hashes of the commits on HEAD since `date` (YYYY-MM-DD), oldest first,
+/// with the parent of the oldest when it has one. None if git can't be run.
+pub fn commits_since(repo_root: &Path, date: &str) -> Option<(Vec<String>, Option<String>)> {
+    let output = Command::new("git")
+        .args(["log", "--reverse", "--format=%h %p"])
+        .arg(format!("--since={} 00:00", date))
+        .current_dir(repo_root)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let log = String::from_utf8_lossy(&output.stdout);
+    let mut commits = Vec::new();
+    let mut base = None;
+    for line in log.lines() {
+        let mut fields = line.split_whitespace();
+        let Some(hash) = fields.next() else {
+            continue;
+        };
+        if commits.is_empty() {
+            base = fields.next().map(str::to_string);
+        }
+        commits.push(hash.to_string());
+    }
+    Some((commits, base))
+}
@@ -1,2 +1,6 @@
+mod branch;
 mod detect;
+mod log;
+pub use branch::default_branch;
 pub use detect::find_repo_root;
+pub use log::commits_since;
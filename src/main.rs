@@ -1,9 +1,12 @@
 use clap::{Parser, Subcommand};
 
 mod commands;
+mod config;
 mod embed;
 mod fs;
 mod git;
+mod stack;
+mod targets;
 mod upgrade;
 
 /// AI workflow template manager for git repositories
@@ -20,24 +23,180 @@ struct Cli {
 enum Commands {
     /// Initialize AI workflow templates in the current git repository
     Init {
-        /// Only install specific categories (comma-separated: ai,rules,commands,hooks,skills,agents)
+        /// Only install specific categories (comma-separated: ai,rules,commands,hooks,skills,agents,
+        /// lang for the detected language rule packs, or a pack name: rust,typescript,python,go)
         #[arg(long, value_delimiter = ',')]
         only: Option<Vec<String>>,
 
-        /// Skip specific categories (comma-separated: ai,rules,commands,hooks,skills,agents)
+        /// Skip specific categories (comma-separated, same values as --only)
         #[arg(long, value_delimiter = ',')]
         skip: Option<Vec<String>>,
 
-        /// Overwrite existing files without prompting
+        /// AI tools to install for (comma-separated: cursor,claude,copilot,agents,windsurf,cline,continue,gemini).
+        /// Defaults to the tools detected in the repository
+        #[arg(long, value_delimiter = ',')]
+        target: Option<Vec<String>>,
+
+        /// Word budget for the ai-init section of AGENTS.md.
+        /// Defaults to the recorded budget, else 1000
+        #[arg(long)]
+        agents_words: Option<usize>,
+
+        /// How Cursor hooks run: native (ai-init hook), powershell or sh.
+        /// Defaults to the installed flavor, else native if ai-init is on PATH,
+        /// else powershell on Windows and sh elsewhere
+        #[arg(long)]
+        hook_shell: Option<String>,
+
+        /// Template variable for installed context files, as key=value (repeatable).
+        /// Overrides detected project_name, date, default_branch and primary_language
+        #[arg(long = "var")]
+        vars: Vec<String>,
+
+        /// Templates to install instead of the embedded set: a directory with the
+        /// public/ layout, a .tar.gz archive, or a git repository (git+<url>[#ref])
+        #[arg(long)]
+        source: Option<String>,
+
+        /// How to treat .gitignore: update (existing file), create, or skip
+        #[arg(long)]
+        gitignore: Option<String>,
+
+        /// When a file already exists: prompt, overwrite or skip
+        #[arg(long)]
+        conflict: Option<String>,
+
+        /// Overwrite existing files without prompting (same as --conflict overwrite)
         #[arg(long, short)]
         force: bool,
     },
 
+    /// Refresh installed files using the settings in .ai-init.toml
+    Update {
+        /// Overwrite changed files without prompting. Context files are always kept
+        #[arg(long, short)]
+        force: bool,
+    },
+
+    /// Work with the project context in .ai/ai-context.md
+    Context {
+        #[command(subcommand)]
+        action: ContextAction,
+    },
+
+    /// Condense .ai/ai-context.md into .ai/ai-summary.md for session start
+    Summarize {
+        /// Word budget for the summary
+        #[arg(long, default_value_t = commands::context::DEFAULT_SUMMARY_WORDS)]
+        words: usize,
+
+        /// Overwrite a hand-written ai-summary.md without prompting
+        #[arg(long, short)]
+        force: bool,
+    },
+
+    /// Record learnings in .ai/ai-context.md
+    Learn {
+        #[command(subcommand)]
+        action: LearnAction,
+    },
+
     /// Check repository for missing or broken AI workflow files
     Doctor {
         /// Automatically fix detected issues
         #[arg(long)]
         fix: bool,
+
+        /// AI tools to check (comma-separated, same values as init).
+        /// Defaults to the tools detected in the repository
+        #[arg(long, value_delimiter = ',')]
+        target: Option<Vec<String>>,
+
+        /// Templates to check against (same values as init). Defaults to the embedded set
+        #[arg(long)]
+        source: Option<String>,
+    },
+
+    /// Show how installed files differ from the templates
+    Diff {
+        /// AI tools to compare (comma-separated, same values as init).
+        /// Defaults to the tools detected in the repository
+        #[arg(long, value_delimiter = ',')]
+        target: Option<Vec<String>>,
+
+        /// Templates to compare against (same values as init). Defaults to the embedded set
+        #[arg(long)]
+        source: Option<String>,
+    },
+
+    /// Start a research, plan or decision document from the templates in .ai/template/
+    New {
+        /// Document kind: research (.ai/active-research.md), plan (.ai/active-plan.md)
+        /// or decision (numbered ADR in .ai/adr/)
+        kind: String,
+
+        /// Short kebab-case name for the task or decision, e.g. cache-invalidation
+        slug: String,
+
+        /// Replace an active document that is still in progress
+        #[arg(long, short)]
+        force: bool,
+    },
+
+    /// Move the finished task's research and plan to .ai/archive/
+    Archive {
+        /// How the task ended, e.g. shipped or "replaced by feature flags".
+        /// Defaults to shipped, aborted or unfinished from the document statuses
+        #[arg(long)]
+        outcome: Option<String>,
+
+        /// Commit range to record, e.g. a1b2c3d..e4f5a6b.
+        /// Defaults to the commits since the task was created
+        #[arg(long)]
+        commits: Option<String>,
+
+        /// Archive a task that is still in progress
+        #[arg(long, short)]
+        force: bool,
+
+        /// Search the archived tasks instead of archiving
+        #[arg(long, conflicts_with_all = ["outcome", "commits", "force"])]
+        search: Option<String>,
+    },
+
+    /// Manage architecture decision records in .ai/adr/
+    Adr {
+        #[command(subcommand)]
+        action: AdrAction,
+    },
+
+    /// Move the active task through research, planning and implementation
+    Workflow {
+        #[command(subcommand)]
+        action: WorkflowAction,
+    },
+
+    /// Run a Cursor hook (reads hook JSON on stdin, writes the response to stdout)
+    Hook {
+        /// Hook event: session-start, after-file-edit or stop
+        event: String,
+
+        /// Token budget for context injected at session start
+        #[arg(long, default_value_t = commands::hook::DEFAULT_TOKEN_BUDGET)]
+        token_budget: usize,
+
+        /// Files under .ai/ to inject at session start, in priority order (comma-separated).
+        /// Defaults to ai-summary.md, falling back to ai-context.md
+        #[arg(long, value_delimiter = ',')]
+        context: Option<Vec<String>>,
+
+        /// Post-edit rule severities as <id>=<off|info|warning|error> (comma-separated)
+        #[arg(long = "rule", value_delimiter = ',')]
+        rules: Option<Vec<String>>,
+
+        /// Only run post-edit rules for these languages (comma-separated). Defaults to all
+        #[arg(long = "lang", value_delimiter = ',')]
+        languages: Option<Vec<String>>,
     },
 
     /// Upgrade ai-init to the latest version
@@ -52,24 +211,228 @@ enum Commands {
         /// Remove files without prompting
         #[arg(long, short)]
         force: bool,
+
+        /// AI tools to remove files for (comma-separated, same values as init).
+        /// Defaults to the tools detected in the repository
+        #[arg(long, value_delimiter = ',')]
+        target: Option<Vec<String>>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ContextAction {
+    /// Draft ai-context.md from an offline scan of the repository
+    Generate {
+        /// Overwrite an existing ai-context.md without prompting
+        #[arg(long, short)]
+        force: bool,
+    },
+
+    /// Estimate the token cost of installed files: always-on, glob-scoped and on-demand
+    Stats {
+        /// AI tools to report on (comma-separated, same values as init).
+        /// Defaults to the tools detected in the repository
+        #[arg(long, value_delimiter = ',')]
+        target: Option<Vec<String>>,
+
+        /// Warn when a target loads more than this many tokens into every session
+        #[arg(long, default_value_t = commands::context::stats::DEFAULT_ALWAYS_ON_BUDGET)]
+        max_always_on: usize,
+
+        /// Warn when a single file is larger than this many tokens
+        #[arg(long, default_value_t = commands::context::stats::DEFAULT_FILE_BUDGET)]
+        max_file: usize,
+    },
+}
+
+#[derive(Subcommand)]
+enum LearnAction {
+    /// Append a dated learning to ai-context.md, unless it is already there
+    Add {
+        /// What was learned
+        text: String,
+
+        /// Where it goes: patterns, gotchas or decisions
+        #[arg(long)]
+        section: String,
+    },
+
+    /// Accept or reject the learnings filled into .ai/learnings-pending.md
+    Review,
+}
+
+#[derive(Subcommand)]
+enum AdrAction {
+    /// Create the next numbered ADR from .ai/template/decision.md
+    New {
+        /// Short kebab-case name for the decision, e.g. use-sqlite
+        slug: String,
+    },
+
+    /// List every ADR with its status
+    List {
+        /// Only show ADRs with this status: proposed, accepted, superseded or deprecated
+        #[arg(long)]
+        status: Option<String>,
+    },
+
+    /// Print an ADR
+    Show {
+        /// ADR number, e.g. 3 or ADR-003
+        number: String,
+    },
+
+    /// Mark an ADR as accepted or deprecated
+    Status {
+        /// ADR number, e.g. 3 or ADR-003
+        number: String,
+
+        /// New status: accepted or deprecated
+        status: String,
+    },
+
+    /// Mark an ADR as superseded and link it with its successor
+    Supersede {
+        /// ADR number being replaced
+        number: String,
+
+        /// Number of the replacing ADR, or a slug to create it
+        by: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum WorkflowAction {
+    /// Show the task in progress and what blocks the next phase
+    Status,
+
+    /// Start a task with a new .ai/active-research.md
+    Start {
+        /// Short kebab-case name for the task, e.g. cache-invalidation
+        slug: String,
+
+        /// Replace documents that are still in progress
+        #[arg(long, short)]
+        force: bool,
     },
+
+    /// Move to the next phase: research → planning → implementing → done
+    Advance,
+
+    /// Mark the task in progress as aborted
+    Abort,
 }
 
 fn main() {
     let cli = Cli::parse();
 
     let result: Result<(), Box<dyn std::error::Error>> = match cli.command {
-        Commands::Init { only, skip, force } => commands::init::run(only, skip, force)
+        Commands::Init {
+            only,
+            skip,
+            target,
+            agents_words,
+            hook_shell,
+            vars,
+            source,
+            gitignore,
+            conflict,
+            force,
+        } => commands::init::run(commands::init::InitOptions {
+            only,
+            skip,
+            target,
+            agents_words,
+            hook_shell,
+            vars,
+            source,
+            gitignore,
+            conflict,
+            force,
+        })
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
+        Commands::Update { force } => {
+            commands::init::update(force).map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+        }
+        Commands::Context { action } => match action {
+            ContextAction::Generate { force } => commands::context::generate(force)
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
+            ContextAction::Stats {
+                target,
+                max_always_on,
+                max_file,
+            } => commands::context::stats(
+                target,
+                commands::context::stats::Thresholds {
+                    always_on: max_always_on,
+                    file: max_file,
+                },
+            )
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
+        },
+        Commands::Summarize { words, force } => commands::context::summarize(words, force)
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
+        Commands::Learn { action } => match action {
+            LearnAction::Add { text, section } => commands::learn::add(text, section),
+            LearnAction::Review => commands::learn::review(),
+        }
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
+        Commands::Doctor {
+            fix,
+            target,
+            source,
+        } => commands::doctor::run(fix, target, source)
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
+        Commands::Diff { target, source } => commands::diff::run(target, source)
             .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
-        Commands::Doctor { fix } => {
-            commands::doctor::run(fix).map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+        Commands::Archive {
+            outcome,
+            commits,
+            force,
+            search,
+        } => match search {
+            Some(query) => commands::archive::search(query),
+            None => commands::archive::run(outcome, commits, force),
+        }
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
+        Commands::Adr { action } => match action {
+            AdrAction::New { slug } => commands::adr::new(slug),
+            AdrAction::List { status } => commands::adr::list(status),
+            AdrAction::Show { number } => commands::adr::show(number),
+            AdrAction::Status { number, status } => commands::adr::status(number, status),
+            AdrAction::Supersede { number, by } => commands::adr::supersede(number, by),
+        }
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
+        Commands::Workflow { action } => match action {
+            WorkflowAction::Status => commands::workflow::status(),
+            WorkflowAction::Start { slug, force } => commands::workflow::start(slug, force),
+            WorkflowAction::Advance => commands::workflow::advance(),
+            WorkflowAction::Abort => commands::workflow::abort(),
         }
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
+        Commands::New { kind, slug, force } => commands::new::run(kind, slug, force)
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
+        Commands::Hook {
+            event,
+            token_budget,
+            context,
+            rules,
+            languages,
+        } => commands::hook::run(
+            event,
+            commands::hook::HookOptions {
+                token_budget,
+                context: context.unwrap_or_default(),
+                rules: rules.unwrap_or_default(),
+                languages: languages.unwrap_or_default(),
+            },
+        )
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
         Commands::Upgrade { yes } => {
             commands::upgrade::run(yes).map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
         }
-        Commands::Uninstall { force } => {
-            commands::uninstall::run(force).map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
-        }
+        Commands::Uninstall { force, target } => commands::uninstall::run(force, target)
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>),
     };
 
     if let Err(e) = result {
@@ -0,0 +1,267 @@
+use super::{Language, Stack};
+use regex::Regex;
+use std::path::Path;
+
+type Detector = fn(&Path, &mut Stack);
+
+/// Manifest detectors, in order of precedence for the primary language
+pub const DETECTORS: &[Detector] = &[cargo, node, python, go, maven, gradle, dotnet];
+
+/// What a dependency tells us about the stack
+enum Found {
+    Framework(&'static str),
+    TestRunner(&'static str),
+}
+
+const CARGO_DEPS: &[(&str, Found)] = &[
+    ("axum", Found::Framework("Axum")),
+    ("actix-web", Found::Framework("Actix Web")),
+    ("rocket", Found::Framework("Rocket")),
+    ("warp", Found::Framework("warp")),
+    ("tokio", Found::Framework("Tokio")),
+    ("clap", Found::Framework("clap")),
+    ("tauri", Found::Framework("Tauri")),
+    ("bevy", Found::Framework("Bevy")),
+    ("leptos", Found::Framework("Leptos")),
+    ("diesel", Found::Framework("Diesel")),
+    ("sqlx", Found::Framework("SQLx")),
+    ("proptest", Found::TestRunner("proptest")),
+    ("insta", Found::TestRunner("insta")),
+];
+
+const NODE_DEPS: &[(&str, Found)] = &[
+    ("next", Found::Framework("Next.js")),
+    ("react", Found::Framework("React")),
+    ("vue", Found::Framework("Vue")),
+    ("nuxt", Found::Framework("Nuxt")),
+    ("svelte", Found::Framework("Svelte")),
+    ("@sveltejs/kit", Found::Framework("SvelteKit")),
+    ("@angular/core", Found::Framework("Angular")),
+    ("@remix-run/react", Found::Framework("Remix")),
+    ("astro", Found::Framework("Astro")),
+    ("express", Found::Framework("Express")),
+    ("fastify", Found::Framework("Fastify")),
+    ("@nestjs/core", Found::Framework("NestJS")),
+    ("electron", Found::Framework("Electron")),
+    ("jest", Found::TestRunner("Jest")),
+    ("vitest", Found::TestRunner("Vitest")),
+    ("mocha", Found::TestRunner("Mocha")),
+    ("@playwright/test", Found::TestRunner("Playwright")),
+    ("cypress", Found::TestRunner("Cypress")),
+];
+
+const PYTHON_DEPS: &[(&str, Found)] = &[
+    ("django", Found::Framework("Django")),
+    ("flask", Found::Framework("Flask")),
+    ("fastapi", Found::Framework("FastAPI")),
+    ("pydantic", Found::Framework("Pydantic")),
+    ("sqlalchemy", Found::Framework("SQLAlchemy")),
+    ("celery", Found::Framework("Celery")),
+    ("pytest", Found::TestRunner("pytest")),
+    ("tox", Found::TestRunner("tox")),
+];
+
+const GO_DEPS: &[(&str, Found)] = &[
+    ("github.com/gin-gonic/gin", Found::Framework("Gin")),
+    ("github.com/labstack/echo", Found::Framework("Echo")),
+    ("github.com/gofiber/fiber", Found::Framework("Fiber")),
+    ("github.com/go-chi/chi", Found::Framework("chi")),
+    ("github.com/spf13/cobra", Found::Framework("Cobra")),
+    ("gorm.io/gorm", Found::Framework("GORM")),
+    ("github.com/stretchr/testify", Found::TestRunner("testify")),
+];
+
+const JVM_DEPS: &[(&str, Found)] = &[
+    ("spring-boot", Found::Framework("Spring Boot")),
+    ("quarkus", Found::Framework("Quarkus")),
+    ("micronaut", Found::Framework("Micronaut")),
+    ("junit-jupiter", Found::TestRunner("JUnit 5")),
+    ("testng", Found::TestRunner("TestNG")),
+];
+
+const DOTNET_DEPS: &[(&str, Found)] = &[
+    ("Microsoft.NET.Sdk.Web", Found::Framework("ASP.NET Core")),
+    (
+        "Microsoft.EntityFrameworkCore",
+        Found::Framework("Entity Framework Core"),
+    ),
+    ("xunit", Found::TestRunner("xUnit")),
+    ("NUnit", Found::TestRunner("NUnit")),
+    ("MSTest", Found::TestRunner("MSTest")),
+];
+
+fn cargo(root: &Path, stack: &mut Stack) {
+    let Some(content) = read(root, "Cargo.toml", stack) else {
+        return;
+    };
+    add_language(stack, Language::Rust);
+    add(&mut stack.build_tools, "cargo");
+    add(&mut stack.test_runners, "cargo test");
+    if root.join(".config/nextest.toml").exists() {
+        add(&mut stack.test_runners, "cargo-nextest");
+    }
+    apply(stack, CARGO_DEPS, |name| toml_has_key(&content, name));
+}
+
+fn node(root: &Path, stack: &mut Stack) {
+    let Some(content) = read(root, "package.json", stack) else {
+        return;
+    };
+    let manifest: serde_json::Value = serde_json::from_str(&content).unwrap_or_default();
+    let has_dep = |name: &str| {
+        ["dependencies", "devDependencies", "peerDependencies"]
+            .iter()
+            .any(|section| manifest[section].get(name).is_some())
+    };
+
+    if has_dep("typescript") || root.join("tsconfig.json").exists() {
+        add_language(stack, Language::TypeScript);
+    } else {
+        add_language(stack, Language::JavaScript);
+    }
+
+    let package_manager = if root.join("pnpm-lock.yaml").exists() {
+        "pnpm"
+    } else if root.join("yarn.lock").exists() {
+        "yarn"
+    } else if root.join("bun.lockb").exists() || root.join("bun.lock").exists() {
+        "bun"
+    } else {
+        "npm"
+    };
+    add(&mut stack.build_tools, package_manager);
+
+    // Next.js depends on React; report only the more specific framework
+    let next = has_dep("next");
+    apply(stack, NODE_DEPS, |name| {
+        has_dep(name) && !(next && name == "react")
+    });
+}
+
+fn python(root: &Path, stack: &mut Stack) {
+    let mut content = String::new();
+    for manifest in ["pyproject.toml", "requirements.txt", "setup.py"] {
+        if let Some(text) = read(root, manifest, stack) {
+            content.push_str(&text.to_lowercase());
+            content.push('\n');
+        }
+    }
+    if content.is_empty() {
+        return;
+    }
+
+    add_language(stack, Language::Python);
+    let build_tool = if content.contains("[tool.poetry]") {
+        "poetry"
+    } else if root.join("uv.lock").exists() {
+        "uv"
+    } else {
+        "pip"
+    };
+    add(&mut stack.build_tools, build_tool);
+    apply(stack, PYTHON_DEPS, |name| {
+        python_has_dep(&content, name) || toml_has_key(&content, name)
+    });
+}
+
+fn go(root: &Path, stack: &mut Stack) {
+    let Some(content) = read(root, "go.mod", stack) else {
+        return;
+    };
+    add_language(stack, Language::Go);
+    add(&mut stack.build_tools, "go");
+    add(&mut stack.test_runners, "go test");
+    apply(stack, GO_DEPS, |name| content.contains(name));
+}
+
+fn maven(root: &Path, stack: &mut Stack) {
+    let Some(content) = read(root, "pom.xml", stack) else {
+        return;
+    };
+    add_language(stack, Language::Java);
+    add(&mut stack.build_tools, "Maven");
+    apply(stack, JVM_DEPS, |name| content.contains(name));
+}
+
+fn gradle(root: &Path, stack: &mut Stack) {
+    let content = ["build.gradle", "build.gradle.kts"]
+        .iter()
+        .find_map(|name| read(root, name, stack));
+    let Some(content) = content else {
+        return;
+    };
+    add_language(stack, Language::Java);
+    add(&mut stack.build_tools, "Gradle");
+    apply(stack, JVM_DEPS, |name| content.contains(name));
+}
+
+fn dotnet(root: &Path, stack: &mut Stack) {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return;
+    };
+    let mut projects: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.file_name().into_string().ok())
+        .filter(|name| name.ends_with(".csproj"))
+        .collect();
+    if projects.is_empty() {
+        return;
+    }
+    projects.sort();
+
+    add_language(stack, Language::CSharp);
+    add(&mut stack.build_tools, "dotnet");
+    add(&mut stack.test_runners, "dotnet test");
+    for project in projects {
+        if let Some(content) = read(root, &project, stack) {
+            apply(stack, DOTNET_DEPS, |name| content.contains(name));
+        }
+    }
+}
+
+/// Read a manifest at the repo root and record it as found
+fn read(root: &Path, name: &str, stack: &mut Stack) -> Option<String> {
+    let content = std::fs::read_to_string(root.join(name)).ok()?;
+    stack.manifests.push(name.to_string());
+    Some(content)
+}
+
+fn apply(stack: &mut Stack, deps: &[(&str, Found)], has_dep: impl Fn(&str) -> bool) {
+    for (name, found) in deps {
+        if !has_dep(name) {
+            continue;
+        }
+        match found {
+            Found::Framework(f) => add(&mut stack.frameworks, f),
+            Found::TestRunner(t) => add(&mut stack.test_runners, t),
+        }
+    }
+}
+
+fn add_language(stack: &mut Stack, language: Language) {
+    if !stack.languages.contains(&language) {
+        stack.languages.push(language);
+    }
+}
+
+fn add(items: &mut Vec<&'static str>, item: &'static str) {
+    if !items.contains(&item) {
+        items.push(item);
+    }
+}
+
+/// A TOML line declaring `name` as a key (`name = ...` or `name.workspace = ...`)
+fn toml_has_key(content: &str, name: &str) -> bool {
+    Regex::new(&format!(r"(?m)^\s*{}\s*[=.]", regex::escape(name)))
+        .is_ok_and(|re| re.is_match(content))
+}
+
+/// A requirement naming `name`: a quoted string such as `"fastapi>=0.110"`,
+/// or a requirements.txt line such as `flask==3.0`
+fn python_has_dep(content: &str, name: &str) -> bool {
+    Regex::new(&format!(
+        r#"(?m)(["']|^\s*){}(\[[^\]]*\])?\s*([<>=!~;,"']|$)"#,
+        regex::escape(name)
+    ))
+    .is_ok_and(|re| re.is_match(content))
+}
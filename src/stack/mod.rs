@@ -0,0 +1,172 @@
+mod manifest;
+
+use std::path::Path;
+
+/// Programming language found through its manifest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    TypeScript,
+    JavaScript,
+    Python,
+    Go,
+    Java,
+    CSharp,
+}
+
+impl Language {
+    /// Identifier used in rule packs and hook settings
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+            Self::TypeScript => "typescript",
+            Self::JavaScript => "javascript",
+            Self::Python => "python",
+            Self::Go => "go",
+            Self::Java => "java",
+            Self::CSharp => "csharp",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "rust" | "rs" => Some(Self::Rust),
+            "typescript" | "ts" => Some(Self::TypeScript),
+            "javascript" | "js" => Some(Self::JavaScript),
+            "python" | "py" => Some(Self::Python),
+            "go" | "golang" => Some(Self::Go),
+            "java" | "kotlin" => Some(Self::Java),
+            "csharp" | "c#" | "cs" | "dotnet" => Some(Self::CSharp),
+            _ => None,
+        }
+    }
+
+    /// Source file extensions, without the dot
+    pub fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            Self::Rust => &["rs"],
+            Self::TypeScript => &["ts", "tsx", "js", "jsx", "mjs", "cjs"],
+            Self::JavaScript => &["js", "jsx", "mjs", "cjs"],
+            Self::Python => &["py"],
+            Self::Go => &["go"],
+            Self::Java => &["java", "kt"],
+            Self::CSharp => &["cs"],
+        }
+    }
+
+    /// Human-readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Rust => "Rust",
+            Self::TypeScript => "TypeScript",
+            Self::JavaScript => "JavaScript",
+            Self::Python => "Python",
+            Self::Go => "Go",
+            Self::Java => "Java",
+            Self::CSharp => "C#",
+        }
+    }
+}
+
+/// What the repository is built with, detected from manifest files
+#[derive(Debug, Clone, Default)]
+pub struct Stack {
+    /// Languages in manifest order; the first is the primary language
+    pub languages: Vec<Language>,
+    pub frameworks: Vec<&'static str>,
+    pub test_runners: Vec<&'static str>,
+    /// Build tools and package managers
+    pub build_tools: Vec<&'static str>,
+    /// Manifest files found, relative to repo root
+    pub manifests: Vec<String>,
+}
+
+impl Stack {
+    pub fn is_empty(&self) -> bool {
+        self.languages.is_empty()
+    }
+
+    pub fn primary_language(&self) -> Option<Language> {
+        self.languages.first().copied()
+    }
+
+    /// One-line description, e.g. "Rust (clap, Tokio; tests: cargo test)"
+    pub fn describe(&self) -> String {
+        let languages: Vec<&str> = self.languages.iter().map(|l| l.name()).collect();
+        let mut details: Vec<String> = Vec::new();
+        if !self.frameworks.is_empty() {
+            details.push(self.frameworks.join(", "));
+        }
+        if !self.test_runners.is_empty() {
+            details.push(format!("tests: {}", self.test_runners.join(", ")));
+        }
+
+        if details.is_empty() {
+            languages.join(", ")
+        } else {
+            format!("{} ({})", languages.join(", "), details.join("; "))
+        }
+    }
+
+    /// Markdown bullets for the System Overview section of ai-context.md
+    pub fn overview(&self) -> String {
+        if self.is_empty() {
+            return "- **Stack**: [Languages, frameworks and key libraries]".to_string();
+        }
+
+        let mut lines = vec![format!(
+            "- **Languages**: {}",
+            self.languages
+                .iter()
+                .map(|l| l.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )];
+        if !self.frameworks.is_empty() {
+            lines.push(format!("- **Frameworks**: {}", self.frameworks.join(", ")));
+        }
+        lines.push(format!(
+            "- **Manifests**: {}",
+            self.manifests
+                .iter()
+                .map(|m| format!("`{}`", m))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        lines.join("\n")
+    }
+
+    /// Markdown bullets for the Tooling section of ai-context.md
+    pub fn tooling(&self) -> String {
+        let list = |items: &[&str], placeholder: &str| {
+            if items.is_empty() {
+                placeholder.to_string()
+            } else {
+                items.join(", ")
+            }
+        };
+
+        [
+            format!(
+                "- **Build**: {}",
+                list(&self.build_tools, "[Build tool and command]")
+            ),
+            format!(
+                "- **Tests**: {}",
+                list(&self.test_runners, "[Test runner and command]")
+            ),
+            "- **Lint/Format**: [Linters and formatters, and how to run them]".to_string(),
+        ]
+        .join("\n")
+    }
+}
+
+/// Detect languages, frameworks, test runners and build tools
+/// from the manifests at the repository root
+pub fn detect(repo_root: &Path) -> Stack {
+    let mut stack = Stack::default();
+    for detector in manifest::DETECTORS {
+        detector(repo_root, &mut stack);
+    }
+    stack
+}
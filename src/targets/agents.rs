@@ -0,0 +1,128 @@
+use super::sources::{self, Rule};
+use super::{GenerateOptions, GeneratedFile, Target, WriteMode};
+use crate::embed::frontmatter;
+use crate::embed::markdown::{condense, demote_headings, strip_title, word_count};
+use crate::embed::template;
+use crate::embed::{Artifact, ArtifactKind};
+
+/// Default word budget for the generated section of AGENTS.md
+pub const DEFAULT_WORD_BUDGET: usize = 1000;
+
+const SUMMARY: &str = "ai-summary.md";
+const WORKFLOW_RULE: &str = "three-phase-workflow";
+
+const HEADER: &str = "# AGENTS.md\n\n\
+Instructions for AI coding agents working in this repository. \
+Sections outside the ai-init markers are yours and are kept when ai-init updates this file.\n\n";
+
+/// AGENTS.md target for tool-agnostic agents (Codex CLI, Jules, Amp, ...)
+pub struct Agents;
+
+impl Target for Agents {
+    fn id(&self) -> &'static str {
+        "agents"
+    }
+
+    fn markers(&self) -> &'static [&'static str] {
+        &["AGENTS.md"]
+    }
+
+    fn layout(&self) -> &'static [(&'static str, ArtifactKind)] {
+        &[("AGENTS.md", ArtifactKind::Rule)]
+    }
+
+    fn render(&self, artifacts: &[&Artifact], options: &GenerateOptions) -> Vec<GeneratedFile> {
+        compose(artifacts, options).into_iter().collect()
+    }
+}
+
+/// Compose AGENTS.md from the project summary, a condensed three-phase workflow
+/// and the `alwaysApply` rules, staying within the configured word budget.
+/// Rules that don't fit in full are condensed, then omitted.
+fn compose(artifacts: &[&Artifact], options: &GenerateOptions) -> Option<GeneratedFile> {
+    let rules: Vec<Rule> = sources::rules(artifacts)
+        .into_iter()
+        .filter(|r| r.always_apply)
+        .collect();
+
+    if rules.is_empty() {
+        return None;
+    }
+
+    let mut sections: Vec<String> = Vec::new();
+    let mut omitted: Vec<&str> = Vec::new();
+    let mut remaining = options.agents_word_budget;
+
+    if let Some(summary) = summary(artifacts, options) {
+        let summary = format!(
+            "## Project Summary\n\n{}",
+            demote_headings(summary_body(&summary), 1)
+        );
+        remaining = remaining.saturating_sub(word_count(&summary));
+        sections.push(summary);
+    }
+
+    if let Some(rule) = rules.iter().find(|r| r.name == WORKFLOW_RULE) {
+        let workflow = demote_headings(&condense(rule.body), 1);
+        remaining = remaining.saturating_sub(word_count(&workflow));
+        sections.push(workflow);
+    }
+
+    for rule in rules.iter().filter(|r| r.name != WORKFLOW_RULE) {
+        let full = demote_headings(rule.body.trim(), 1);
+        let condensed = demote_headings(&condense(rule.body), 1);
+
+        if word_count(&full) <= remaining {
+            remaining -= word_count(&full);
+            sections.push(full);
+        } else if word_count(&condensed) <= remaining {
+            remaining -= word_count(&condensed);
+            sections.push(condensed);
+        } else {
+            omitted.push(rule.name);
+        }
+    }
+
+    if !omitted.is_empty() {
+        sections.push(format!(
+            "_Omitted to stay within the word budget: {}._",
+            omitted.join(", ")
+        ));
+    }
+
+    Some(GeneratedFile {
+        target_path: "AGENTS.md".to_string(),
+        content: sections
+            .iter()
+            .map(|s| s.trim())
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        mode: WriteMode::ManagedBlock { header: HEADER },
+    })
+}
+
+/// The repo's own summary so edits to it flow into AGENTS.md,
+/// else the override or template that init installs as `.ai/ai-summary.md`
+fn summary(artifacts: &[&Artifact], options: &GenerateOptions) -> Option<String> {
+    let path = format!(".ai/{}", SUMMARY);
+    if let Ok(summary) = std::fs::read_to_string(options.repo_root.join(&path)) {
+        return Some(summary);
+    }
+    let template = options.overrides.get(&path).or_else(|| {
+        artifacts
+            .iter()
+            .find(|a| a.kind == ArtifactKind::Context && a.name == SUMMARY)
+            .map(|a| a.content.as_ref())
+    })?;
+    Some(template::render(template, &options.vars))
+}
+
+/// Summary without its title, purpose note, or trailing `---` footer
+fn summary_body(summary: &str) -> &str {
+    let (_, body) = frontmatter::parse(summary);
+    let body = strip_title(body);
+    match body.find("\n---") {
+        Some(end) => &body[..end],
+        None => body,
+    }
+}
@@ -0,0 +1,87 @@
+use super::sources::{self, frontmatter_block, quoted, yaml_list};
+use super::{GenerateOptions, GeneratedFile, Target, WriteMode};
+use crate::embed::markdown::demote_headings;
+use crate::embed::{Artifact, ArtifactKind};
+
+const HEADER: &str = "# CLAUDE.md\n\n\
+Context for Claude Code in this repository. \
+Sections outside the ai-init markers are yours and are kept when ai-init updates this file.\n\n";
+
+/// Claude Code target: `alwaysApply` rules are merged into `CLAUDE.md`,
+/// glob-scoped rules go to `.claude/rules/` with `paths`, and commands,
+/// agents and skills map onto their `.claude/` equivalents.
+pub struct Claude;
+
+impl Target for Claude {
+    fn id(&self) -> &'static str {
+        "claude"
+    }
+
+    fn markers(&self) -> &'static [&'static str] {
+        &["CLAUDE.md", ".claude/"]
+    }
+
+    fn layout(&self) -> &'static [(&'static str, ArtifactKind)] {
+        &[
+            ("CLAUDE.md", ArtifactKind::Rule),
+            (".claude/rules/", ArtifactKind::Rule),
+            (".claude/commands/", ArtifactKind::Command),
+            (".claude/agents/", ArtifactKind::Agent),
+            (".claude/skills/", ArtifactKind::Skill),
+        ]
+    }
+
+    fn render(&self, artifacts: &[&Artifact], _options: &GenerateOptions) -> Vec<GeneratedFile> {
+        let mut result = Vec::new();
+        let mut always_on: Vec<String> = Vec::new();
+
+        for rule in sources::rules(artifacts) {
+            if rule.always_apply || rule.globs.is_empty() {
+                always_on.push(demote_headings(rule.body.trim(), 1));
+                continue;
+            }
+
+            result.push(GeneratedFile {
+                target_path: format!(".claude/rules/{}.md", rule.name),
+                content: frontmatter_block(&[("paths", yaml_list(&rule.globs))]) + rule.body,
+                mode: WriteMode::Replace,
+            });
+        }
+
+        if !always_on.is_empty() {
+            result.insert(
+                0,
+                GeneratedFile {
+                    target_path: "CLAUDE.md".to_string(),
+                    content: always_on.join("\n"),
+                    mode: WriteMode::ManagedBlock { header: HEADER },
+                },
+            );
+        }
+
+        for command in sources::commands(artifacts) {
+            let header = frontmatter_block(&[("description", quoted(&command.description))]);
+            result.push(GeneratedFile {
+                target_path: format!(".claude/commands/{}.md", command.name),
+                content: header + command.body,
+                mode: WriteMode::Replace,
+            });
+        }
+
+        // Subagents and skills share Cursor's name/description frontmatter
+        for artifact in artifacts {
+            let dir = match artifact.kind {
+                ArtifactKind::Agent => "agents",
+                ArtifactKind::Skill => "skills",
+                _ => continue,
+            };
+            result.push(GeneratedFile {
+                target_path: format!(".claude/{}/{}", dir, artifact.name),
+                content: artifact.content.to_string(),
+                mode: WriteMode::Replace,
+            });
+        }
+
+        result
+    }
+}
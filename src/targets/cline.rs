@@ -0,0 +1,74 @@
+use super::sources::{self, frontmatter_block, yaml_list};
+use super::{GenerateOptions, GeneratedFile, Target, WriteMode};
+use crate::embed::{Artifact, ArtifactKind};
+use std::path::Path;
+
+/// Rule the legacy single-file `.clinerules` is moved to
+const LEGACY_RULE: &str = ".clinerules/project-rules.md";
+
+/// Cline/Roo target: rules go to `.clinerules/` (glob-scoped ones get a
+/// `paths` list), commands become `.clinerules/workflows/`.
+pub struct Cline;
+
+impl Target for Cline {
+    fn id(&self) -> &'static str {
+        "cline"
+    }
+
+    fn markers(&self) -> &'static [&'static str] {
+        &[".clinerules", ".roo/"]
+    }
+
+    fn layout(&self) -> &'static [(&'static str, ArtifactKind)] {
+        &[
+            (".clinerules/workflows/", ArtifactKind::Command),
+            (".clinerules/", ArtifactKind::Rule),
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["roo"]
+    }
+
+    /// Cline also reads a single `.clinerules` file; keep its rules as one file in the directory
+    fn migrate(&self, repo_root: &Path) -> std::io::Result<Vec<String>> {
+        let legacy = repo_root.join(".clinerules");
+        if !legacy.is_file() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&legacy)?;
+        std::fs::remove_file(&legacy)?;
+        std::fs::create_dir_all(&legacy)?;
+        std::fs::write(repo_root.join(LEGACY_RULE), content)?;
+        Ok(vec![format!("Moved .clinerules to {}", LEGACY_RULE)])
+    }
+
+    fn render(&self, artifacts: &[&Artifact], _options: &GenerateOptions) -> Vec<GeneratedFile> {
+        let mut result = Vec::new();
+
+        for rule in sources::rules(artifacts) {
+            // Rules without paths are always active in Cline
+            let content = if rule.always_apply || rule.globs.is_empty() {
+                rule.body.to_string()
+            } else {
+                frontmatter_block(&[("paths", yaml_list(&rule.globs))]) + rule.body
+            };
+
+            result.push(GeneratedFile {
+                target_path: format!(".clinerules/{}.md", rule.name),
+                content,
+                mode: WriteMode::Replace,
+            });
+        }
+
+        for command in sources::commands(artifacts) {
+            result.push(GeneratedFile {
+                target_path: format!(".clinerules/workflows/{}.md", command.name),
+                content: command.body.to_string(),
+                mode: WriteMode::Replace,
+            });
+        }
+
+        result
+    }
+}
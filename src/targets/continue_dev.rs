@@ -0,0 +1,62 @@
+use super::sources::{self, frontmatter_block, quoted, yaml_list};
+use super::{GenerateOptions, GeneratedFile, Target, WriteMode};
+use crate::embed::{Artifact, ArtifactKind};
+
+/// Continue target: rules go to `.continue/rules/` with `globs` and
+/// `alwaysApply`, commands become invokable prompts in `.continue/prompts/`.
+pub struct Continue;
+
+impl Target for Continue {
+    fn id(&self) -> &'static str {
+        "continue"
+    }
+
+    fn markers(&self) -> &'static [&'static str] {
+        &[".continue/"]
+    }
+
+    fn layout(&self) -> &'static [(&'static str, ArtifactKind)] {
+        &[
+            (".continue/rules/", ArtifactKind::Rule),
+            (".continue/prompts/", ArtifactKind::Command),
+        ]
+    }
+
+    fn render(&self, artifacts: &[&Artifact], _options: &GenerateOptions) -> Vec<GeneratedFile> {
+        let mut result = Vec::new();
+
+        for rule in sources::rules(artifacts) {
+            let header = frontmatter_block(&[
+                ("name", quoted(rule.name)),
+                (
+                    "description",
+                    quoted(rule.description.as_deref().unwrap_or_default()),
+                ),
+                ("globs", yaml_list(&rule.globs)),
+                ("alwaysApply", rule.always_apply.to_string()),
+            ]);
+
+            result.push(GeneratedFile {
+                target_path: format!(".continue/rules/{}.md", rule.name),
+                content: header + rule.body,
+                mode: WriteMode::Replace,
+            });
+        }
+
+        for command in sources::commands(artifacts) {
+            let header = frontmatter_block(&[
+                ("name", quoted(command.name)),
+                ("description", quoted(&command.description)),
+                ("invokable", "true".to_string()),
+            ]);
+
+            result.push(GeneratedFile {
+                target_path: format!(".continue/prompts/{}.md", command.name),
+                content: header + command.body,
+                mode: WriteMode::Replace,
+            });
+        }
+
+        result
+    }
+}
@@ -0,0 +1,89 @@
+use super::sources::{self, frontmatter_block, quoted};
+use super::{GenerateOptions, GeneratedFile, Target, WriteMode};
+use crate::embed::{Artifact, ArtifactKind};
+
+/// GitHub Copilot target:
+/// - `alwaysApply` rules are merged into `.github/copilot-instructions.md`
+/// - other rules become `.github/instructions/<name>.instructions.md` with `applyTo`
+/// - commands become `.github/prompts/<name>.prompt.md`
+pub struct Copilot;
+
+impl Target for Copilot {
+    fn id(&self) -> &'static str {
+        "copilot"
+    }
+
+    fn markers(&self) -> &'static [&'static str] {
+        &[".github/copilot-instructions.md", ".github/instructions/"]
+    }
+
+    fn layout(&self) -> &'static [(&'static str, ArtifactKind)] {
+        &[
+            (".github/copilot-instructions.md", ArtifactKind::Rule),
+            (".github/instructions/", ArtifactKind::Rule),
+            (".github/prompts/", ArtifactKind::Command),
+        ]
+    }
+
+    fn render(&self, artifacts: &[&Artifact], _options: &GenerateOptions) -> Vec<GeneratedFile> {
+        let mut result = Vec::new();
+        let mut always_on: Vec<&str> = Vec::new();
+
+        for rule in sources::rules(artifacts) {
+            if rule.always_apply {
+                always_on.push(rule.body.trim());
+                continue;
+            }
+
+            let header = frontmatter_block(&[
+                (
+                    "description",
+                    quoted(rule.description.as_deref().unwrap_or_default()),
+                ),
+                ("applyTo", quoted(&rule.globs.join(","))),
+            ]);
+
+            result.push(GeneratedFile {
+                target_path: format!(".github/instructions/{}.instructions.md", rule.name),
+                content: header + rule.body,
+                mode: WriteMode::Replace,
+            });
+        }
+
+        for command in sources::commands(artifacts) {
+            let header = frontmatter_block(&[
+                ("mode", "agent".to_string()),
+                ("description", quoted(&command.description)),
+            ]);
+
+            result.push(GeneratedFile {
+                target_path: format!(".github/prompts/{}.prompt.md", command.name),
+                content: header + command.body,
+                mode: WriteMode::Replace,
+            });
+        }
+
+        if !always_on.is_empty() {
+            let mut content = String::from(
+                    "# Copilot Instructions\n\n\
+                     <!-- Generated by ai-init from the workflow rules. Re-run `ai-init init --target copilot` to refresh. -->\n",
+                );
+            for body in always_on {
+                content.push('\n');
+                content.push_str(body);
+                content.push('\n');
+            }
+
+            result.insert(
+                0,
+                GeneratedFile {
+                    target_path: ".github/copilot-instructions.md".to_string(),
+                    content,
+                    mode: WriteMode::Replace,
+                },
+            );
+        }
+
+        result
+    }
+}
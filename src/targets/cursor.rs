@@ -0,0 +1,115 @@
+use super::{GenerateOptions, GeneratedFile, HookShell, Target, WriteMode};
+use crate::embed::{Artifact, ArtifactKind};
+use crate::stack::Stack;
+
+/// Hook scripts and the `ai-init hook` events that replace them
+const NATIVE_HOOKS: &[(&str, &str)] = &[
+    ("inject-context", "session-start"),
+    ("post-edit-check", "after-file-edit"),
+    ("extract-learnings", "stop"),
+];
+
+/// Cursor target: artifacts are written as-is under `.cursor/`.
+/// `hooks.json` command lines point at the selected hook flavor,
+/// and only scripts of that flavor are installed.
+pub struct Cursor;
+
+impl Target for Cursor {
+    fn id(&self) -> &'static str {
+        "cursor"
+    }
+
+    fn markers(&self) -> &'static [&'static str] {
+        &[".cursor/", ".cursorrules"]
+    }
+
+    fn layout(&self) -> &'static [(&'static str, ArtifactKind)] {
+        &[
+            (".cursor/rules/", ArtifactKind::Rule),
+            (".cursor/commands/", ArtifactKind::Command),
+            (".cursor/agents/", ArtifactKind::Agent),
+            (".cursor/skills/", ArtifactKind::Skill),
+            (".cursor/hooks", ArtifactKind::Hook),
+        ]
+    }
+
+    fn render(&self, artifacts: &[&Artifact], options: &GenerateOptions) -> Vec<GeneratedFile> {
+        let shell = options.hook_shell;
+
+        artifacts
+            .iter()
+            .filter_map(|a| {
+                let (dir, content) = match a.kind {
+                    ArtifactKind::Rule => ("rules/", a.content.to_string()),
+                    ArtifactKind::Command => ("commands/", a.content.to_string()),
+                    ArtifactKind::Agent => ("agents/", a.content.to_string()),
+                    ArtifactKind::Skill => ("skills/", a.content.to_string()),
+                    // hooks.json sits next to the hooks/ directory it points into
+                    ArtifactKind::Hook if a.name == "hooks.json" => {
+                        ("", hooks_json(&a.content, shell, &options.stack))
+                    }
+                    ArtifactKind::Hook
+                        if shell
+                            .script_extension()
+                            .is_some_and(|ext| a.name.ends_with(ext)) =>
+                    {
+                        ("hooks/", a.content.to_string())
+                    }
+                    ArtifactKind::Hook | ArtifactKind::Context => return None,
+                };
+                Some(GeneratedFile {
+                    target_path: format!(".cursor/{}{}", dir, a.name),
+                    content,
+                    mode: WriteMode::Replace,
+                })
+            })
+            .collect()
+    }
+
+    fn gitignore_entries(&self) -> &'static [&'static str] {
+        &[".cursor/"]
+    }
+}
+
+/// Point hooks.json command lines at the selected hook flavor.
+/// The embedded file references the PowerShell scripts; POSIX scripts run via `sh`
+/// so they work without the executable bit. Native post-edit checks are limited
+/// to the detected languages.
+fn hooks_json(content: &str, shell: HookShell, stack: &Stack) -> String {
+    let languages: Vec<&str> = stack.languages.iter().map(|l| l.as_str()).collect();
+
+    if shell == HookShell::PowerShell {
+        return content.to_string();
+    }
+
+    content
+        .lines()
+        .map(|line| {
+            let Some((prefix, rest)) = line.split_once("\"command\": \".cursor/hooks/") else {
+                return line.to_string();
+            };
+            let command = match shell {
+                HookShell::Native => NATIVE_HOOKS
+                    .iter()
+                    .find(|(script, _)| rest.starts_with(&format!("{}.ps1\"", script)))
+                    .map(|(script, event)| {
+                        let mut command = format!("ai-init hook {}", event);
+                        if *event == "after-file-edit" && !languages.is_empty() {
+                            command.push_str(&format!(" --lang {}", languages.join(",")));
+                        }
+                        rest.replacen(&format!("{}.ps1", script), &command, 1)
+                    }),
+                _ => Some(format!(
+                    "sh .cursor/hooks/{}",
+                    rest.replacen(".ps1\"", ".sh\"", 1)
+                )),
+            };
+            match command {
+                Some(command) => format!("{}\"command\": \"{}", prefix, command),
+                None => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
@@ -0,0 +1,40 @@
+use super::Target;
+use std::path::Path;
+
+/// A target whose marker files were found in the repository
+pub struct Detection {
+    pub target: Box<dyn Target>,
+    /// Markers that matched (e.g., ".cursor/", "CLAUDE.md")
+    pub found: Vec<&'static str>,
+}
+
+/// Find the AI tools a repository already uses by their marker files.
+/// Markers ending in `/` must be directories.
+pub fn detect(repo_root: &Path) -> Vec<Detection> {
+    super::all()
+        .into_iter()
+        .filter_map(|target| {
+            let found: Vec<&'static str> = target
+                .markers()
+                .iter()
+                .copied()
+                .filter(|marker| {
+                    let path = repo_root.join(marker.trim_end_matches('/'));
+                    if marker.ends_with('/') {
+                        path.is_dir()
+                    } else {
+                        path.exists()
+                    }
+                })
+                .collect();
+            (!found.is_empty()).then_some(Detection { target, found })
+        })
+        .collect()
+}
+
+impl Detection {
+    /// "cursor (.cursor/, .cursorrules)"
+    pub fn describe(&self) -> String {
+        format!("{} ({})", self.target.id(), self.found.join(", "))
+    }
+}
@@ -0,0 +1,89 @@
+use super::sources;
+use super::{GenerateOptions, GeneratedFile, Target, WriteMode};
+use crate::embed::markdown::demote_headings;
+use crate::embed::{Artifact, ArtifactKind};
+use serde::Serialize;
+
+const HEADER: &str = "# GEMINI.md\n\n\
+Context for Gemini CLI in this repository. \
+Sections outside the ai-init markers are yours and are kept when ai-init updates this file.\n\n";
+
+/// Gemini CLI target: rules are merged into `GEMINI.md`, glob-scoped ones with a note naming their globs;
+/// commands become TOML custom commands in `.gemini/commands/`.
+pub struct Gemini;
+
+impl Target for Gemini {
+    fn id(&self) -> &'static str {
+        "gemini"
+    }
+
+    fn markers(&self) -> &'static [&'static str] {
+        &["GEMINI.md", ".gemini/"]
+    }
+
+    fn layout(&self) -> &'static [(&'static str, ArtifactKind)] {
+        &[
+            ("GEMINI.md", ArtifactKind::Rule),
+            (".gemini/commands/", ArtifactKind::Command),
+        ]
+    }
+
+    fn render(&self, artifacts: &[&Artifact], _options: &GenerateOptions) -> Vec<GeneratedFile> {
+        let mut result = Vec::new();
+
+        // Gemini CLI has no per-path rules, so scoped rules say where they apply
+        let rules: Vec<String> = sources::rules(artifacts)
+            .iter()
+            .map(|r| {
+                let body = demote_headings(r.body.trim(), 1);
+                if r.always_apply || r.globs.is_empty() {
+                    body
+                } else {
+                    scoped(&body, &r.globs)
+                }
+            })
+            .collect();
+
+        if !rules.is_empty() {
+            result.push(GeneratedFile {
+                target_path: "GEMINI.md".to_string(),
+                content: rules.join("\n"),
+                mode: WriteMode::ManagedBlock { header: HEADER },
+            });
+        }
+
+        for command in sources::commands(artifacts) {
+            let toml = CustomCommand {
+                description: &command.description,
+                prompt: command.body.trim_end(),
+            };
+            result.push(GeneratedFile {
+                target_path: format!(".gemini/commands/{}.toml", command.name),
+                content: toml::to_string(&toml).expect("a table of strings serializes"),
+                mode: WriteMode::Replace,
+            });
+        }
+
+        result
+    }
+}
+
+/// A Gemini CLI custom command. Arguments passed to the command are appended
+/// by Gemini CLI since the prompt has no `{{args}}` placeholder.
+#[derive(Serialize)]
+struct CustomCommand<'a> {
+    description: &'a str,
+    prompt: &'a str,
+}
+
+/// Glob-scoped rule body with a note naming the files it covers, below its title
+fn scoped(body: &str, globs: &[String]) -> String {
+    let globs: Vec<String> = globs.iter().map(|g| format!("`{}`", g)).collect();
+    let note = format!("> Applies to files matching {}\n", globs.join(", "));
+    match body.split_once('\n') {
+        Some((title, rest)) if title.starts_with('#') => {
+            format!("{}\n\n{}\n{}", title, note, rest.trim_start_matches('\n'))
+        }
+        _ => format!("{}\n{}", note, body),
+    }
+}
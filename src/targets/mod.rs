@@ -0,0 +1,312 @@
+pub mod agents;
+mod claude;
+mod cline;
+mod continue_dev;
+mod copilot;
+mod cursor;
+mod detect;
+mod gemini;
+pub mod overrides;
+mod sources;
+mod windsurf;
+
+use crate::config::{Config, ConfigError};
+use crate::embed::template::{self, Vars};
+use crate::embed::{Artifact, ArtifactKind, Category};
+use crate::fs::{read_managed_block, BLOCK_BEGIN};
+use crate::stack::{self, Stack};
+
+pub use detect::{detect, Detection};
+pub use overrides::{Origin, Overrides};
+use std::path::Path;
+
+/// An AI tool that workflow artifacts can be installed for.
+/// Each implementation maps tool-neutral artifacts to that tool's paths and formats.
+pub trait Target {
+    /// Identifier used with --target
+    fn id(&self) -> &'static str;
+
+    /// Alternative identifiers accepted by --target
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Render rule, command, hook, skill and agent artifacts into files for this tool.
+    /// Context artifacts are passed for reference; `generate` writes them once for every target.
+    fn render(&self, artifacts: &[&Artifact], options: &GenerateOptions) -> Vec<GeneratedFile>;
+
+    /// Entries added to .gitignore when this target is installed
+    fn gitignore_entries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Paths whose presence shows the repo already uses this tool
+    fn markers(&self) -> &'static [&'static str];
+
+    /// Path prefixes this target writes, with the kind of artifact each holds
+    fn layout(&self) -> &'static [(&'static str, ArtifactKind)];
+
+    /// Move files of an older layout out of the way before installing.
+    /// Returns a line describing each move.
+    fn migrate(&self, _repo_root: &Path) -> std::io::Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Returns every supported target
+pub fn all() -> Vec<Box<dyn Target>> {
+    vec![
+        Box::new(cursor::Cursor),
+        Box::new(claude::Claude),
+        Box::new(copilot::Copilot),
+        Box::new(agents::Agents),
+        Box::new(windsurf::Windsurf),
+        Box::new(cline::Cline),
+        Box::new(continue_dev::Continue),
+        Box::new(gemini::Gemini),
+    ]
+}
+
+/// Target used when none is given and none is detected
+pub const DEFAULT_TARGET: &str = "cursor";
+
+/// Comma-separated list of target ids, for error messages
+pub fn valid_ids() -> String {
+    all().iter().map(|t| t.id()).collect::<Vec<_>>().join(", ")
+}
+
+/// Resolve --target values, dropping duplicates.
+/// Returns the first unknown id as the error.
+pub fn parse(ids: &[String]) -> Result<Vec<Box<dyn Target>>, String> {
+    let mut result: Vec<Box<dyn Target>> = Vec::new();
+    for id in ids {
+        let id_lower = id.to_lowercase();
+        let target = all()
+            .into_iter()
+            .find(|t| t.id() == id_lower || t.aliases().contains(&id_lower.as_str()))
+            .ok_or_else(|| id.clone())?;
+        if !result.iter().any(|t| t.id() == target.id()) {
+            result.push(target);
+        }
+    }
+    Ok(result)
+}
+
+/// Targets from --target if given, otherwise the tools detected in the repo,
+/// falling back to the default target
+pub fn resolve(ids: Option<Vec<String>>, repo_root: &Path) -> Result<Vec<Box<dyn Target>>, String> {
+    if let Some(ids) = ids {
+        return parse(&ids);
+    }
+
+    let detected: Vec<Box<dyn Target>> = detect(repo_root).into_iter().map(|d| d.target).collect();
+    if detected.is_empty() {
+        parse(&[DEFAULT_TARGET.to_string()])
+    } else {
+        Ok(detected)
+    }
+}
+
+/// .gitignore entries for the given targets, including the shared `.ai/` directory
+pub fn gitignore_entries(targets: &[Box<dyn Target>]) -> Vec<&'static str> {
+    let mut entries = vec![".ai/"];
+    for target in targets {
+        entries.extend(target.gitignore_entries());
+    }
+    entries
+}
+
+/// Context files the team fills in after install. Expected to differ from the
+/// templates, and never replaced by `update`.
+pub const PROJECT_FILES: &[&str] = &[".ai/ai-context.md", ".ai/ai-summary.md"];
+
+/// How a generated file is written into the repository
+pub enum WriteMode {
+    /// Replace the whole file, prompting if it exists
+    Replace,
+    /// Only own the section between ai-init markers; `header` seeds new files
+    ManagedBlock { header: &'static str },
+}
+
+/// File rendered for a target, with its path relative to repo root
+pub struct GeneratedFile {
+    pub target_path: String,
+    pub content: String,
+    pub mode: WriteMode,
+}
+
+impl GeneratedFile {
+    /// Whether the file is installed: it exists, and managed files still carry the ai-init section
+    pub fn is_present(&self, repo_root: &Path) -> bool {
+        let path = repo_root.join(&self.target_path);
+        match self.mode {
+            WriteMode::Replace => path.exists(),
+            WriteMode::ManagedBlock { .. } => std::fs::read_to_string(&path)
+                .map(|c| c.contains(BLOCK_BEGIN))
+                .unwrap_or(false),
+        }
+    }
+
+    /// Whether the installed file matches what would be generated now
+    pub fn is_current(&self, repo_root: &Path) -> bool {
+        let path = repo_root.join(&self.target_path);
+        match self.mode {
+            WriteMode::Replace => std::fs::read_to_string(&path)
+                .map(|c| c == self.content)
+                .unwrap_or(false),
+            WriteMode::ManagedBlock { .. } => read_managed_block(&path)
+                .map(|block| block == self.content.trim_end())
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// How editor hooks run: the `ai-init hook` subcommand or bundled scripts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookShell {
+    Native,     // ai-init hook <event>
+    PowerShell, // .ps1
+    Posix,      // .sh
+}
+
+impl HookShell {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "native" | "ai-init" => Some(Self::Native),
+            "powershell" | "pwsh" | "ps1" => Some(Self::PowerShell),
+            "sh" | "posix" | "bash" => Some(Self::Posix),
+            _ => None,
+        }
+    }
+
+    /// Extension of the hook scripts to install; native hooks need none
+    pub fn script_extension(&self) -> Option<&'static str> {
+        match self {
+            Self::Native => None,
+            Self::PowerShell => Some(".ps1"),
+            Self::Posix => Some(".sh"),
+        }
+    }
+
+    /// The flavor already referenced by `.cursor/hooks.json`, else native when
+    /// `ai-init` is on PATH, else the platform's script flavor
+    pub fn detect(repo_root: &Path) -> Self {
+        let hooks =
+            std::fs::read_to_string(repo_root.join(".cursor/hooks.json")).unwrap_or_default();
+        if hooks.contains(".ps1") {
+            Self::PowerShell
+        } else if hooks.contains(".sh\"") {
+            Self::Posix
+        } else if hooks.contains("ai-init hook") || native_available() {
+            Self::Native
+        } else if cfg!(windows) {
+            Self::PowerShell
+        } else {
+            Self::Posix
+        }
+    }
+}
+
+/// Whether native hooks can find `ai-init` on PATH
+fn native_available() -> bool {
+    let Some(path) = std::env::var_os("PATH") else {
+        return false;
+    };
+    let name = format!("ai-init{}", std::env::consts::EXE_SUFFIX);
+    std::env::split_paths(&path).any(|dir| dir.join(&name).is_file())
+}
+
+/// Settings that shape generated output
+pub struct GenerateOptions<'a> {
+    pub repo_root: &'a Path,
+    /// Word budget for the ai-init section of AGENTS.md
+    pub agents_word_budget: usize,
+    /// How installed hooks run
+    pub hook_shell: HookShell,
+    /// Languages and tools detected from the repository's manifests
+    pub stack: Stack,
+    /// Values for `{{name}}` placeholders in context files
+    pub vars: Vars,
+    /// User files that shadow or add to the generated files
+    pub overrides: Overrides,
+    /// Categories selected by --only/--skip, which extra override files follow too
+    pub only: Option<Vec<Category>>,
+    pub skip: Option<Vec<Category>>,
+}
+
+impl<'a> GenerateOptions<'a> {
+    pub fn new(repo_root: &'a Path) -> Self {
+        let stack = stack::detect(repo_root);
+        Self {
+            repo_root,
+            agents_word_budget: agents::DEFAULT_WORD_BUDGET,
+            hook_shell: HookShell::detect(repo_root),
+            vars: Vars::detect(repo_root, &stack),
+            stack,
+            overrides: Overrides::load(),
+            only: None,
+            skip: None,
+        }
+    }
+
+    /// Options for the settings recorded in `.ai-init.toml`, as init would use them
+    pub fn from_config(repo_root: &'a Path, config: &Config) -> Result<Self, ConfigError> {
+        let (only, skip) = config.categories()?;
+        let mut options = Self {
+            only,
+            skip,
+            ..Self::new(repo_root)
+        };
+        if let Some(words) = config.agents_words {
+            options.agents_word_budget = words;
+        }
+        if let Some(shell) = &config.hook_shell {
+            options.hook_shell = HookShell::from_str(shell)
+                .ok_or_else(|| ConfigError::InvalidHookShell(shell.clone()))?;
+        }
+        for (key, value) in &config.vars {
+            options.vars.set(key, value);
+        }
+        Ok(options)
+    }
+}
+
+/// Render artifacts for the given targets, with user overrides applied
+pub fn generate(
+    targets: &[Box<dyn Target>],
+    artifacts: &[Artifact],
+    options: &GenerateOptions,
+) -> Vec<GeneratedFile> {
+    generate_with_origins(targets, artifacts, options)
+        .into_iter()
+        .map(|(file, _)| file)
+        .collect()
+}
+
+/// Render artifacts for the given targets, noting where each file comes from.
+/// Context artifacts are shared by every target and emitted once under `.ai/`,
+/// with their `{{name}}` placeholders filled in. Files in the override directory
+/// then shadow their generated counterparts or are added as extra files.
+pub fn generate_with_origins(
+    targets: &[Box<dyn Target>],
+    artifacts: &[Artifact],
+    options: &GenerateOptions,
+) -> Vec<(GeneratedFile, Origin)> {
+    let artifacts: Vec<&Artifact> = artifacts.iter().collect();
+
+    let mut result: Vec<GeneratedFile> = artifacts
+        .iter()
+        .filter(|a| a.kind == ArtifactKind::Context)
+        .map(|a| GeneratedFile {
+            target_path: format!(".ai/{}", a.name),
+            content: template::render(&a.content, &options.vars),
+            mode: WriteMode::Replace,
+        })
+        .collect();
+
+    for target in targets {
+        result.extend(target.render(&artifacts, options));
+    }
+
+    options.overrides.apply(result, targets, options)
+}
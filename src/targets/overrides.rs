@@ -0,0 +1,133 @@
+use super::{GenerateOptions, GeneratedFile, Target, WriteMode};
+use crate::embed::source::read_dir;
+use crate::embed::template;
+use crate::embed::{ArtifactKind, Category};
+use std::path::PathBuf;
+
+/// Where a file in the effective set comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    Template, // rendered from the embedded or --source templates
+    Override, // user file shadowing a template file
+    Extra,    // user file with no template counterpart
+}
+
+impl Origin {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Template => "template",
+            Self::Override => "override",
+            Self::Extra => "extra",
+        }
+    }
+}
+
+/// Personal or org-wide files under `~/.config/ai-init/templates/`, keyed by target path
+#[derive(Debug, Clone, Default)]
+pub struct Overrides {
+    files: Vec<(String, String)>,
+}
+
+impl Overrides {
+    /// Directory mirroring the repository layout, e.g. `templates/.cursor/rules/core-principles.mdc`
+    pub fn dir() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".config/ai-init/templates"))
+    }
+
+    /// Read the override directory; missing or unreadable means no overrides
+    pub fn load() -> Self {
+        let mut files = Self::dir()
+            .filter(|dir| dir.is_dir())
+            .and_then(|dir| read_dir(&dir).ok())
+            .unwrap_or_default();
+        files.retain(|(path, _)| !path.ends_with(".DS_Store"));
+        files.sort();
+        Self { files }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Target paths of every override file
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.files.iter().map(|(path, _)| path.as_str())
+    }
+
+    /// Content of the override for `path`, if any
+    pub fn get(&self, path: &str) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, content)| content.as_str())
+    }
+
+    /// Replace generated files with their overrides and append the extra files
+    /// that belong to the selected targets and categories.
+    /// `.ai/` overrides get the same `{{name}}` substitution as the templates.
+    pub fn apply(
+        &self,
+        files: Vec<GeneratedFile>,
+        targets: &[Box<dyn Target>],
+        options: &GenerateOptions,
+    ) -> Vec<(GeneratedFile, Origin)> {
+        let mut result: Vec<(GeneratedFile, Origin)> =
+            files.into_iter().map(|f| (f, Origin::Template)).collect();
+
+        for (path, content) in &self.files {
+            let content = if path.starts_with(".ai/") {
+                template::render(content, &options.vars)
+            } else {
+                content.clone()
+            };
+
+            match result.iter_mut().find(|(f, _)| f.target_path == *path) {
+                Some((file, origin)) => {
+                    file.content = content;
+                    *origin = Origin::Override;
+                }
+                None if in_scope(path, targets, options) => result.push((
+                    GeneratedFile {
+                        target_path: path.clone(),
+                        content,
+                        mode: WriteMode::Replace,
+                    },
+                    Origin::Extra,
+                )),
+                None => {}
+            }
+        }
+
+        result
+    }
+}
+
+/// Whether an extra file passes the same target and --only/--skip selection as the
+/// generated files. `.ai/` files are shared by every target; files in no target's
+/// layout aren't tied to a tool or category and are always kept.
+fn in_scope(path: &str, targets: &[Box<dyn Target>], options: &GenerateOptions) -> bool {
+    let kind = if path.starts_with(".ai/") {
+        ArtifactKind::Context
+    } else {
+        match owner(path) {
+            Some((id, kind)) if targets.iter().any(|t| t.id() == id) => kind,
+            Some(_) => return false,
+            None => return true,
+        }
+    };
+
+    let category = Category::Kind(kind);
+    options.only.as_ref().is_none_or(|o| o.contains(&category))
+        && !options.skip.as_ref().is_some_and(|s| s.contains(&category))
+}
+
+/// Target whose layout holds `path`, with the kind of artifact found there
+pub fn owner(path: &str) -> Option<(&'static str, ArtifactKind)> {
+    super::all().into_iter().find_map(|target| {
+        let (_, kind) = target
+            .layout()
+            .iter()
+            .find(|(prefix, _)| path.starts_with(prefix))?;
+        Some((target.id(), *kind))
+    })
+}
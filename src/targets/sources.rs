@@ -0,0 +1,84 @@
+use crate::embed::frontmatter;
+use crate::embed::markdown::{file_stem, summary_line};
+use crate::embed::{Artifact, ArtifactKind};
+
+/// A `.mdc` rule with its frontmatter resolved
+pub struct Rule<'a> {
+    pub name: &'a str,
+    pub description: Option<String>,
+    pub globs: Vec<String>,
+    pub always_apply: bool,
+    pub body: &'a str,
+}
+
+/// A command prompt with a one-line description derived from its body
+pub struct Command<'a> {
+    pub name: &'a str,
+    pub description: String,
+    pub body: &'a str,
+}
+
+pub fn rules<'a>(artifacts: &[&'a Artifact]) -> Vec<Rule<'a>> {
+    artifacts
+        .iter()
+        .filter(|a| a.kind == ArtifactKind::Rule)
+        .map(|a| {
+            let (meta, body) = frontmatter::parse(&a.content);
+            let meta = meta.unwrap_or_default();
+            Rule {
+                name: file_stem(&a.name),
+                description: meta.get_str("description").map(str::to_string),
+                globs: meta.get_list("globs"),
+                always_apply: meta.get_bool("alwaysApply"),
+                body: body.trim_start(),
+            }
+        })
+        .collect()
+}
+
+pub fn commands<'a>(artifacts: &[&'a Artifact]) -> Vec<Command<'a>> {
+    artifacts
+        .iter()
+        .filter(|a| a.kind == ArtifactKind::Command)
+        .map(|a| {
+            let (_, body) = frontmatter::parse(&a.content);
+            Command {
+                name: file_stem(&a.name),
+                description: summary_line(body),
+                body: body.trim_start(),
+            }
+        })
+        .collect()
+}
+
+/// Render a frontmatter block from `key: value` pairs, skipping empty values.
+/// Values from `yaml_list` are written as block lists under their key.
+pub fn frontmatter_block(fields: &[(&str, String)]) -> String {
+    let mut block = String::from("---\n");
+    for (key, value) in fields.iter().filter(|(_, v)| !v.is_empty()) {
+        if value.starts_with('\n') {
+            block.push_str(&format!("{}:{}\n", key, value));
+        } else {
+            block.push_str(&format!("{}: {}\n", key, value));
+        }
+    }
+    block.push_str("---\n\n");
+    block
+}
+
+/// Double-quote a YAML scalar, swapping inner double quotes for single ones.
+/// Empty values stay empty so `frontmatter_block` skips them.
+pub fn quoted(value: &str) -> String {
+    if value.is_empty() {
+        return String::new();
+    }
+    format!("\"{}\"", value.replace('"', "'"))
+}
+
+/// Render items as a YAML block list value for `frontmatter_block`
+pub fn yaml_list(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|item| format!("\n  - {}", quoted(item)))
+        .collect()
+}
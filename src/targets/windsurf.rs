@@ -0,0 +1,67 @@
+use super::sources::{self, frontmatter_block, quoted};
+use super::{GenerateOptions, GeneratedFile, Target, WriteMode};
+use crate::embed::{Artifact, ArtifactKind};
+
+/// Windsurf target: rules go to `.windsurf/rules/` with a `trigger`
+/// matching the Cursor activation mode, commands become `.windsurf/workflows/`.
+pub struct Windsurf;
+
+impl Target for Windsurf {
+    fn id(&self) -> &'static str {
+        "windsurf"
+    }
+
+    fn markers(&self) -> &'static [&'static str] {
+        &[".windsurf/", ".windsurfrules"]
+    }
+
+    fn layout(&self) -> &'static [(&'static str, ArtifactKind)] {
+        &[
+            (".windsurf/rules/", ArtifactKind::Rule),
+            (".windsurf/workflows/", ArtifactKind::Command),
+        ]
+    }
+
+    fn render(&self, artifacts: &[&Artifact], _options: &GenerateOptions) -> Vec<GeneratedFile> {
+        let mut result = Vec::new();
+
+        for rule in sources::rules(artifacts) {
+            let trigger = if rule.always_apply {
+                "always_on"
+            } else if !rule.globs.is_empty() {
+                "glob"
+            } else if rule.description.is_some() {
+                "model_decision"
+            } else {
+                "manual"
+            };
+
+            let header = frontmatter_block(&[
+                ("trigger", trigger.to_string()),
+                (
+                    "description",
+                    quoted(rule.description.as_deref().unwrap_or_default()),
+                ),
+                ("globs", quoted(&rule.globs.join(","))),
+            ]);
+
+            result.push(GeneratedFile {
+                target_path: format!(".windsurf/rules/{}.md", rule.name),
+                content: header + rule.body,
+                mode: WriteMode::Replace,
+            });
+        }
+
+        for command in sources::commands(artifacts) {
+            let header = frontmatter_block(&[("description", quoted(&command.description))]);
+
+            result.push(GeneratedFile {
+                target_path: format!(".windsurf/workflows/{}.md", command.name),
+                content: header + command.body,
+                mode: WriteMode::Replace,
+            });
+        }
+
+        result
+    }
+}
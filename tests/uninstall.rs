@@ -0,0 +1,58 @@
+use assert_cmd::Command;
+use std::path::Path;
+
+fn ai_init(repo: &Path, args: &[&str]) -> assert_cmd::assert::Assert {
+    Command::new(assert_cmd::cargo::cargo_bin!("ai-init"))
+        .current_dir(repo)
+        .env("HOME", repo.join(".home"))
+        .args(args)
+        .assert()
+}
+
+#[test]
+fn uninstall_removes_files_stamped_on_an_earlier_day() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path();
+    std::process::Command::new("git")
+        .args(["init", "--quiet"])
+        .current_dir(repo)
+        .status()
+        .unwrap();
+
+    ai_init(repo, &["init", "--target", "cursor", "--force"]).success();
+
+    // Move the install date back, as if init had run on another day
+    let context = repo.join(".ai/ai-context.md");
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let installed = std::fs::read_to_string(&context).unwrap();
+    assert!(installed.contains(&today));
+    std::fs::write(&context, installed.replace(&today, "2020-01-01")).unwrap();
+
+    ai_init(repo, &["uninstall", "--force"]).success();
+
+    assert!(!context.exists());
+    assert!(!repo.join(".cursor/rules").exists());
+}
+
+#[test]
+fn uninstall_keeps_edited_files_with_force() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path();
+    std::process::Command::new("git")
+        .args(["init", "--quiet"])
+        .current_dir(repo)
+        .status()
+        .unwrap();
+
+    ai_init(repo, &["init", "--target", "cursor", "--force"]).success();
+
+    let context = repo.join(".ai/ai-context.md");
+    let installed = std::fs::read_to_string(&context).unwrap();
+    std::fs::write(&context, format!("{}\nProject notes.\n", installed)).unwrap();
+
+    ai_init(repo, &["uninstall", "--force"])
+        .success()
+        .stdout(predicates::str::contains("Kept .ai/ai-context.md"));
+
+    assert!(context.exists());
+}